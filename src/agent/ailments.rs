@@ -0,0 +1,115 @@
+#[derive(Copy, Clone, PartialEq)]
+pub enum AilmentKind {
+    TwistedAnkle,
+    BrokenArm,
+    StomachAche,
+    Poisoning,
+    Fever,
+    Bite,
+    Mauling,
+    Bruises,
+}
+
+impl AilmentKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            AilmentKind::TwistedAnkle => "twisted ankle",
+            AilmentKind::BrokenArm => "broken arm",
+            AilmentKind::StomachAche => "stomach ache",
+            AilmentKind::Poisoning => "poisoning",
+            AilmentKind::Fever => "fever",
+            AilmentKind::Bite => "bite",
+            AilmentKind::Mauling => "mauling",
+            AilmentKind::Bruises => "beating",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<AilmentKind> {
+        match name {
+            "twisted ankle" => Some(AilmentKind::TwistedAnkle),
+            "broken arm" => Some(AilmentKind::BrokenArm),
+            "stomach ache" => Some(AilmentKind::StomachAche),
+            "poisoning" => Some(AilmentKind::Poisoning),
+            "fever" => Some(AilmentKind::Fever),
+            "bite" => Some(AilmentKind::Bite),
+            "mauling" => Some(AilmentKind::Mauling),
+            "beating" => Some(AilmentKind::Bruises),
+            _ => None,
+        }
+    }
+
+    pub fn onset(&self) -> &'static str {
+        match self {
+            AilmentKind::TwistedAnkle => "Stumbled on a root and twisted an ankle.",
+            AilmentKind::BrokenArm => "Fell down a slope and broke an arm.",
+            AilmentKind::StomachAche => "Got a stomach ache.",
+            AilmentKind::Poisoning => "Doubled over with cramps. Something eaten was poisonous.",
+            AilmentKind::Fever => "Came down with a fever.",
+            AilmentKind::Bite => "Was left with a deep bite.",
+            AilmentKind::Mauling => "Was badly mauled.",
+            AilmentKind::Bruises => "Was left bruised and bloody.",
+        }
+    }
+
+    // How much pain per hour the ailment causes.
+    pub fn severity(&self) -> f64 {
+        match self {
+            AilmentKind::TwistedAnkle => 0.5,
+            AilmentKind::BrokenArm => 1.5,
+            AilmentKind::StomachAche => 0.5,
+            AilmentKind::Poisoning => 3.0,
+            AilmentKind::Fever => 2.0,
+            AilmentKind::Bite => 1.0,
+            AilmentKind::Mauling => 2.5,
+            AilmentKind::Bruises => 0.5,
+        }
+    }
+
+    // Hours until recovery for an agent who is up and about.
+    pub fn duration(&self) -> f64 {
+        match self {
+            AilmentKind::TwistedAnkle => 24.0 * 3.0,
+            AilmentKind::BrokenArm => 24.0 * 30.0,
+            AilmentKind::StomachAche => 12.0,
+            AilmentKind::Poisoning => 48.0,
+            AilmentKind::Fever => 24.0 * 5.0,
+            AilmentKind::Bite => 24.0 * 5.0,
+            AilmentKind::Mauling => 24.0 * 10.0,
+            AilmentKind::Bruises => 24.0 * 2.0,
+        }
+    }
+
+    // Chance of the ailment killing an agent over its whole course. Kept
+    // apart from severity so long but painful injuries aren't death sentences.
+    pub fn lethality(&self) -> f64 {
+        match self {
+            AilmentKind::TwistedAnkle => 0.005,
+            AilmentKind::BrokenArm => 0.01,
+            AilmentKind::StomachAche => 0.001,
+            AilmentKind::Poisoning => 0.03,
+            AilmentKind::Fever => 0.05,
+            AilmentKind::Bite => 0.025,
+            AilmentKind::Mauling => 0.12,
+            AilmentKind::Bruises => 0.005,
+        }
+    }
+
+    pub fn contagious(&self) -> bool {
+        matches!(self, AilmentKind::Fever)
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct Ailment {
+    pub kind: AilmentKind,
+    pub remaining: f64,
+}
+
+impl Ailment {
+    pub fn new(kind: AilmentKind) -> Ailment {
+        Ailment {
+            kind: kind,
+            remaining: kind.duration(),
+        }
+    }
+}
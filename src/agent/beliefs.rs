@@ -0,0 +1,39 @@
+use super::AgentId;
+use super::super::LocationId;
+use super::super::items::ItemTypeId;
+
+// Something an agent thinks is true about the world. It may not be.
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Claim {
+    FoodAt { location: LocationId, kind: ItemTypeId },
+    Dead { agent: AgentId },
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Source {
+    Witnessed,
+    HeardFrom(AgentId),
+}
+
+#[derive(Copy, Clone)]
+pub struct Belief {
+    pub source: Source,
+    pub confidence: f64,
+    pub since: f64,
+}
+
+impl Belief {
+    pub fn witnessed(time: f64) -> Belief {
+        Belief {
+            source: Source::Witnessed,
+            confidence: 1.0,
+            since: time,
+        }
+    }
+
+    // How sure the agent still is. Everything gets hazier with age, even
+    // what they saw for themselves.
+    pub fn confidence_at(&self, time: f64) -> f64 {
+        self.confidence * 0.9f64.powf((time - self.since) / 24.0)
+    }
+}
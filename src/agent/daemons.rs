@@ -1,13 +1,19 @@
 use std::cell::Cell;
-use std::collections::hash_map::Entry;
+use rand::Rng;
 
-use super::{Agent, AgentId, Event, World};
+use super::{Agent, AgentId, Event, ItemId, World};
+use super::super::{DummyEvent, Weather};
 use super::executive;
 use super::events;
+use super::personality::Personality;
+use super::professions::Profession;
+use super::ailments::AilmentKind;
+use super::memories::{Memory, MemoryId, MemoryKind};
+use super::super::items::{Category, RECIPES};
 
 pub trait Daemon {
     fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64>;
-    fn events(&self, agent: &Agent, world: &World) -> Vec<Box<dyn Event>> {
+    fn events(&self, _agent: &Agent, _world: &World) -> Vec<Box<dyn Event>> {
         vec![]
     }
 }
@@ -19,14 +25,16 @@ pub struct Wanderlust {
 
 impl Daemon for Wanderlust {
     fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
-        let min_wait = 5.0;
-        let max_wait = 50.0;
+        let curiosity = Personality::scale(agent.personality.curiosity);
+        let min_wait = 5.0 / curiosity;
+        let max_wait = 50.0 / curiosity;
+        let max_urgency = 0.5 * Personality::scale(agent.personality.bravery);
         let wait = world.time - self.last_wander.get();
 
         if wait > min_wait {
             let mut mind = agent.mind.borrow_mut();
             let goal = mind.goals.entry(executive::Goal::Explore).or_insert(0.0);
-            *goal = (wait / max_wait).min(0.5);
+            *goal = (wait / max_wait).min(max_urgency);
         }
         None
     }
@@ -42,10 +50,11 @@ impl Daemon for HungerTracker {
             health.hunger += 0.25;
         }
 
-        if health.hunger > 5.0 {
+        let appetite = Personality::scale(agent.personality.appetite);
+        if health.hunger > 5.0 / appetite {
             let mut mind = agent.mind.borrow_mut();
             let goal = mind.goals.entry(executive::Goal::FindFood).or_insert(0.0);
-            *goal += 0.5;
+            *goal += 0.5 * appetite;
         }
 
         if health.hunger > 10.0 {
@@ -60,7 +69,174 @@ impl Daemon for HungerTracker {
         }
     }
 
-    fn events(&self, agent: &Agent, world: &World) -> Vec<Box<dyn Event>> {
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
+        vec![
+            Box::new(events::DieEvent { agent: agent.id })
+        ]
+    }
+}
+
+// Keeps a few days of food in the stockpile at home when there's nothing
+// more pressing to do.
+pub struct Provider;
+impl Daemon for Provider {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        let health = agent.health.borrow();
+        let mut mind = agent.mind.borrow_mut();
+        let stockpile: f64 = world.locations[agent.home].items.values()
+            .filter(|i| i.kind().is(Category::Food) && !i.spoiled(world.time))
+            .map(|i| i.kind().food_value)
+            .sum();
+        let wanted = 24.0 * 3.0 * Personality::scale(agent.personality.appetite);
+
+        if health.hunger < 5.0 && stockpile < wanted {
+            let goal = mind.goals.entry(executive::Goal::Gather).or_insert(0.0);
+            *goal = (*goal + 0.01).min(0.3);
+        } else {
+            mind.goals.remove(&executive::Goal::Gather);
+        }
+        None
+    }
+}
+
+// Idle hands want something to make.
+pub struct Crafter;
+impl Daemon for Crafter {
+    fn step_simulation(&self, agent: &Agent, _: &World) -> Option<f64> {
+        let health = agent.health.borrow();
+        let mut mind = agent.mind.borrow_mut();
+        let wanted = RECIPES.iter().any(|r| !agent.has(r.output));
+
+        if health.hunger < 5.0 && wanted {
+            let goal = mind.goals.entry(executive::Goal::Craft).or_insert(0.0);
+            *goal = 0.1 * Personality::scale(agent.personality.curiosity);
+        } else {
+            mind.goals.remove(&executive::Goal::Craft);
+        }
+        None
+    }
+}
+
+// Villagers with a trade put in a shift once a day.
+pub struct Workday;
+impl Daemon for Workday {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        if agent.profession.is_none() || agent.profession == Some(Profession::Farmer) {
+            return None;
+        }
+        if agent.profession == Some(Profession::Trader) && world.storehouses().len() < 2 {
+            return None;
+        }
+        let health = agent.health.borrow();
+        let mut mind = agent.mind.borrow_mut();
+        let hour = world.hour();
+        let working_hours = (8.0..17.0).contains(&hour);
+
+        // A shift that has started runs on until the goods are delivered.
+        let on_shift = mind.current_goal.as_ref().is_some_and(|g| g.0 == executive::Goal::Work);
+        if on_shift || working_hours && mind.last_workday < world.day() && health.hunger < 10.0 && health.sleepiness < 1.0 {
+            let goal = mind.goals.entry(executive::Goal::Work).or_insert(0.0);
+            *goal = 0.6;
+        } else {
+            mind.goals.remove(&executive::Goal::Work);
+        }
+        None
+    }
+}
+
+pub struct Farming;
+impl Daemon for Farming {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        if agent.profession != Some(Profession::Farmer) {
+            return None;
+        }
+        let health = agent.health.borrow();
+        let mut mind = agent.mind.borrow_mut();
+        let hour = world.hour();
+        let daylight = (6.0..20.0).contains(&hour);
+
+        if daylight && health.hunger < 10.0 && health.sleepiness < 1.0 && executive::field_needing_work(agent, world).is_some() {
+            let goal = mind.goals.entry(executive::Goal::Farm).or_insert(0.0);
+            *goal = 0.6;
+        } else {
+            mind.goals.remove(&executive::Goal::Farm);
+        }
+        None
+    }
+}
+
+pub struct ThirstTracker;
+impl Daemon for ThirstTracker {
+    fn step_simulation(&self, agent: &Agent, _: &World) -> Option<f64> {
+        let mut health = agent.health.borrow_mut();
+        if health.awake {
+            health.thirst += 1.0;
+        } else {
+            health.thirst += 0.25;
+        }
+
+        if health.thirst > 4.0 {
+            let mut mind = agent.mind.borrow_mut();
+            let goal = mind.goals.entry(executive::Goal::Drink).or_insert(0.0);
+            // Thirst kills within days, so it soon crowds out everything else.
+            *goal += 0.5 * (1.0 + health.thirst / 24.0);
+        }
+
+        if health.thirst > 8.0 {
+            health.pain += 0.2;
+        }
+
+        if health.thirst > 24.0 * 3.0 {
+            Some(10.0)
+        } else {
+            None
+        }
+    }
+
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
+        vec![
+            Box::new(events::DieEvent { agent: agent.id })
+        ]
+    }
+}
+
+pub const COMFORTABLE_TEMPERATURE: f64 = 15.0;
+// Below this a body loses heat faster than it can make it.
+pub const SEVERE_COLD: f64 = 5.0;
+
+pub struct WarmthTracker;
+impl Daemon for WarmthTracker {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        let mut health = agent.health.borrow_mut();
+        let ambient = world.temperature_at(agent.location) + agent.clothing_warmth();
+        if ambient < SEVERE_COLD {
+            health.temperature -= (SEVERE_COLD - ambient) / 60.0;
+        } else {
+            // A chilly spell only slows the body's return to normal.
+            let recovery = if ambient < COMFORTABLE_TEMPERATURE { 0.1 } else { 0.25 };
+            health.temperature = (health.temperature + recovery).min(37.0);
+        }
+
+        let mut mind = agent.mind.borrow_mut();
+        if health.temperature < 36.5 && ambient < COMFORTABLE_TEMPERATURE {
+            let goal = mind.goals.entry(executive::Goal::KeepWarm).or_insert(0.0);
+            *goal += 0.5;
+        } else {
+            mind.goals.remove(&executive::Goal::KeepWarm);
+        }
+
+        if health.temperature < 35.0 {
+            health.pain += 0.2;
+        }
+
+        if health.temperature < 30.0 {
+            Some(10.0)
+        } else {
+            None
+        }
+    }
+
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
         vec![
             Box::new(events::DieEvent { agent: agent.id })
         ]
@@ -85,7 +261,7 @@ impl Daemon for SleepTracker {
             let goal = mind.goals.entry(executive::Goal::Rest).or_insert(0.0);
             *goal += 0.5;
         } else {
-            let goal = mind.goals.remove(&executive::Goal::Rest);
+            mind.goals.remove(&executive::Goal::Rest);
         }
 
         if health.sleepiness > 24.0 {
@@ -95,7 +271,7 @@ impl Daemon for SleepTracker {
         }
     }
 
-    fn events(&self, agent: &Agent, world: &World) -> Vec<Box<dyn Event>> {
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
         if agent.health.borrow().sleepiness > 1.0 {
             vec![
                 Box::new(events::NapEvent { agent: agent.id })
@@ -137,7 +313,7 @@ impl Daemon for PoopTracker {
         }
     }
 
-    fn events(&self, agent: &Agent, world: &World) -> Vec<Box<dyn Event>> {
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
         vec![
             Box::new(events::DefecateEvent { agent: agent.id })
         ]
@@ -149,10 +325,12 @@ impl Daemon for PainTracker {
     fn step_simulation(&self, agent: &Agent, _: &World) -> Option<f64> {
         let health = agent.health.borrow_mut();
 
+        let mut mind = agent.mind.borrow_mut();
         if health.pain > 0.0 {
-            let mut mind = agent.mind.borrow_mut();
             mind.agitation += 0.1;
             mind.cheer -= 0.1;
+        } else {
+            mind.agitation *= 0.95;
         }
         None
     }
@@ -170,20 +348,490 @@ impl EncounterTracker {
 }
 impl Daemon for EncounterTracker {
     fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
-        let mut mind = agent.mind.borrow_mut();
-        let cheer = mind.cheer;
+        let mind = agent.mind.borrow();
         for a in &world.locations[agent.location].agents {
             if (*a != agent.id) & !mind.opinions_on_others.contains_key(a) {
                 self.encounter.set(Some(*a));
-                return Some(0.25);
+                return Some(0.25 * Personality::scale(agent.personality.sociability));
             }
         }
         None
     }
 
-    fn events(&self, agent: &Agent, world: &World) -> Vec<Box<dyn Event>> {
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
         vec![
             Box::new(events::MeetEvent { agent: agent.id, other: self.encounter.get().unwrap() })
         ]
     }
 }
+
+#[derive(Copy, Clone)]
+enum AilmentOutcome {
+    Onset(AilmentKind),
+    Recovered(AilmentKind),
+    Died(AilmentKind),
+}
+
+pub struct AilmentTracker {
+    outcome: Cell<Option<AilmentOutcome>>,
+}
+impl AilmentTracker {
+    pub fn new() -> AilmentTracker {
+        AilmentTracker {
+            outcome: Cell::new(None),
+        }
+    }
+}
+impl Daemon for AilmentTracker {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        let mut rng = rand::thread_rng();
+        let mut health = agent.health.borrow_mut();
+        let healing = if health.awake { 1.0 } else { 2.0 };
+
+        let mut outcome = None;
+        let mut pain = 0.0;
+        for ailment in health.ailments.iter_mut() {
+            ailment.remaining -= healing;
+            pain += ailment.kind.severity() * 0.1;
+            if rng.gen::<f64>() < ailment.kind.lethality() / ailment.kind.duration() {
+                outcome = Some(AilmentOutcome::Died(ailment.kind));
+            } else if ailment.remaining <= 0.0 && outcome.is_none() {
+                outcome = Some(AilmentOutcome::Recovered(ailment.kind));
+            }
+        }
+        if health.ailments.is_empty() {
+            health.pain = (health.pain - 0.05).max(0.0);
+        } else {
+            health.pain += pain;
+        }
+
+        if outcome.is_none() && !health.is_contagious() {
+            let exposed = world.locations[agent.location].agents.iter()
+                .filter(|a| **a != agent.id)
+                .any(|a| {
+                    let health = world.agents[*a].health.borrow();
+                    health.alive && health.is_contagious()
+                });
+            if (exposed && rng.gen_range(0, 50) == 0) || rng.gen_range(0, 24 * 360) == 0 {
+                outcome = Some(AilmentOutcome::Onset(AilmentKind::Fever));
+            }
+        }
+
+        self.outcome.set(outcome);
+        outcome.map(|_| 10.0)
+    }
+
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
+        match self.outcome.get() {
+            Some(AilmentOutcome::Onset(kind)) => vec![
+                Box::new(events::AfflictEvent { agent: agent.id, kind: kind }),
+            ],
+            Some(AilmentOutcome::Recovered(kind)) => vec![
+                Box::new(events::RecoverEvent { agent: agent.id, kind: kind }),
+            ],
+            Some(AilmentOutcome::Died(kind)) => vec![
+                Box::new(DummyEvent { agent: agent.id, message: format!("Succumbed to a {}.", kind.name()).to_string() }),
+                Box::new(events::DieEvent { agent: agent.id }),
+            ],
+            None => vec![],
+        }
+    }
+}
+
+pub struct Caretaker {
+    patient: Cell<Option<AgentId>>,
+}
+impl Caretaker {
+    pub fn new() -> Caretaker {
+        Caretaker {
+            patient: Cell::new(None),
+        }
+    }
+}
+impl Daemon for Caretaker {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        if !agent.health.borrow().awake {
+            return None;
+        }
+
+        let mind = agent.mind.borrow();
+        for a in &world.locations[agent.location].agents {
+            // Only people they know and don't dislike.
+            match mind.opinions_on_others.get(a) {
+                Some(opinion) if *a != agent.id && *opinion >= 0.0 => (),
+                _ => continue,
+            }
+            let health = world.agents[*a].health.borrow();
+            if health.alive && !health.ailments.is_empty() {
+                self.patient.set(Some(*a));
+                return Some(0.5 * Personality::scale(agent.personality.sociability));
+            }
+        }
+        None
+    }
+
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
+        vec![
+            Box::new(events::CareEvent { agent: agent.id, patient: self.patient.get().unwrap() })
+        ]
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Offer {
+    Gift { other: AgentId, item: ItemId },
+    Barter { other: AgentId, give: ItemId, receive: ItemId },
+}
+
+// Hungry agents with nothing to eat ask around, and generous ones share.
+pub struct Trader {
+    offer: Cell<Option<Offer>>,
+}
+impl Trader {
+    pub fn new() -> Trader {
+        Trader {
+            offer: Cell::new(None),
+        }
+    }
+}
+impl Daemon for Trader {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        if !agent.health.borrow().awake {
+            return None;
+        }
+
+        let mind = agent.mind.borrow();
+        let hungry = agent.health.borrow().hunger > 10.0;
+        let my_food = agent.carried_food_value(world.time);
+        for a in &world.locations[agent.location].agents {
+            if *a == agent.id {
+                continue;
+            }
+            let other = &world.agents[*a];
+            let other_health = other.health.borrow();
+            if !other_health.alive || !other_health.awake {
+                continue;
+            }
+            let opinion = *mind.opinions_on_others.get(a).unwrap_or(&0.0);
+            let other_food = other.carried_food_value(world.time);
+
+            if other_health.hunger > 10.0 && other_food <= 0.0 && my_food > 24.0 && opinion > 0.0 {
+                let gift = agent.inventory()
+                    .filter(|i| i.kind().is(Category::Food) && !i.spoiled(world.time))
+                    .min_by(|x, y| x.kind().value.partial_cmp(&y.kind().value).unwrap());
+                if let Some(item) = gift {
+                    self.offer.set(Some(Offer::Gift { other: *a, item: item.id }));
+                    return Some(0.2 * Personality::scale(agent.personality.sociability) * opinion.min(2.0));
+                }
+            }
+
+            if hungry && my_food < 24.0 && other_food > 0.0 {
+                let their_opinion = *other.mind.borrow().opinions_on_others.get(&agent.id).unwrap_or(&0.0);
+                let receive = other.inventory()
+                    .filter(|i| i.kind().is(Category::Food) && !i.spoiled(world.time) && agent.can_carry(i))
+                    .max_by(|x, y| x.kind().food_value.partial_cmp(&y.kind().food_value).unwrap());
+                let receive = match receive {
+                    Some(item) => item,
+                    None => continue,
+                };
+                // Hungry people drive a harder bargain for food, and won't
+                // trade away their last meal for something they can't eat.
+                let their_need = 1.0 + other_health.hunger / 24.0;
+                let last_meal = other_health.hunger > 10.0 && other_food - receive.kind().food_value < 24.0;
+                // Friends will take a worse deal.
+                let asking = receive.kind().value * their_need * (1.0 - 0.1 * their_opinion).max(0.0);
+                let give = agent.inventory()
+                    .filter(|i| i.kind().value >= asking && i.kind != receive.kind && other.can_carry(i))
+                    .filter(|i| if i.kind().is(Category::Food) {
+                        // Swapping food only helps if it's more filling.
+                        i.kind().food_value < receive.kind().food_value
+                    } else {
+                        !last_meal
+                    })
+                    .min_by(|x, y| x.kind().value.partial_cmp(&y.kind().value).unwrap());
+                if let Some(give) = give {
+                    self.offer.set(Some(Offer::Barter { other: *a, give: give.id, receive: receive.id }));
+                    return Some(1.0);
+                }
+            }
+        }
+        None
+    }
+
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
+        match self.offer.get() {
+            Some(Offer::Gift { other, item }) => vec![
+                Box::new(events::GiftEvent { agent: agent.id, other: other, item: item }),
+            ],
+            Some(Offer::Barter { other, give, receive }) => vec![
+                Box::new(events::TradeEvent { agent: agent.id, other: other, give: give, receive: receive }),
+            ],
+            None => vec![],
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Hostility {
+    Argue(AgentId),
+    Steal(AgentId, ItemId),
+    Fight(AgentId),
+    Avoid(AgentId),
+}
+
+// Turns dislike into behavior: the timid keep away, the hungry steal and
+// the agitated pick quarrels or fights.
+pub struct Antagonism {
+    action: Cell<Option<Hostility>>,
+}
+impl Antagonism {
+    pub fn new() -> Antagonism {
+        Antagonism {
+            action: Cell::new(None),
+        }
+    }
+}
+impl Daemon for Antagonism {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        let health = agent.health.borrow();
+        if !health.awake {
+            return None;
+        }
+        let mind = agent.mind.borrow();
+        let enemy = world.locations[agent.location].agents.iter()
+            .filter(|a| **a != agent.id && world.agents[**a].health.borrow().alive)
+            .map(|a| (*a, *mind.opinions_on_others.get(a).unwrap_or(&0.0)))
+            .filter(|(_, opinion)| *opinion < -1.0)
+            .min_by(|x, y| x.1.partial_cmp(&y.1).unwrap());
+        let (other, opinion) = enemy?;
+        let spite = -opinion;
+
+        let bravery = agent.personality.bravery;
+        let action = if bravery < 0.3 {
+            Hostility::Avoid(other)
+        } else if mind.agitation > 1.0 && bravery > 0.6 {
+            Hostility::Fight(other)
+        } else {
+            let loot = world.agents[other].inventory()
+                .find(|i| i.kind().is(Category::Food) && agent.can_carry(i))
+                .map(|i| i.id);
+            match loot {
+                Some(item) if health.hunger > 10.0 && agent.carried_food_value(world.time) <= 0.0 => Hostility::Steal(other, item),
+                _ => Hostility::Argue(other),
+            }
+        };
+        self.action.set(Some(action));
+        Some((0.1 * spite * Personality::scale(mind.agitation.min(1.0))).min(2.0))
+    }
+
+    fn events(&self, agent: &Agent, world: &World) -> Vec<Box<dyn Event>> {
+        match self.action.get() {
+            Some(Hostility::Argue(other)) => vec![
+                Box::new(events::ArgueEvent { agent: agent.id, other: other }),
+            ],
+            Some(Hostility::Steal(other, item)) => vec![
+                Box::new(events::StealEvent { agent: agent.id, other: other, item: item }),
+            ],
+            Some(Hostility::Fight(other)) => vec![
+                Box::new(events::FightEvent { agent: agent.id, other: other }),
+            ],
+            Some(Hostility::Avoid(other)) => vec![
+                Box::new(DummyEvent { agent: agent.id, message: format!("Slipped away to avoid {}.", world.agents[other].name).to_string() }),
+                executive::wander(agent, world),
+            ],
+            None => vec![],
+        }
+    }
+}
+
+pub struct Loneliness;
+impl Daemon for Loneliness {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        let mut mind = agent.mind.borrow_mut();
+        let company = world.locations[agent.location].agents.iter()
+            .filter(|a| **a != agent.id)
+            .any(|a| {
+                let health = world.agents[*a].health.borrow();
+                health.alive && health.awake
+            });
+        if company {
+            mind.loneliness = (mind.loneliness - 0.25).max(0.0);
+        } else {
+            let sociability = Personality::scale(agent.personality.sociability);
+            let introversion = Personality::scale(agent.personality.introversion);
+            mind.loneliness += sociability / introversion / 24.0;
+        }
+
+        if mind.loneliness > 1.0 {
+            let urgency = (0.2 * mind.loneliness).min(1.0);
+            let goal = mind.goals.entry(executive::Goal::Socialize).or_insert(0.0);
+            *goal = urgency;
+        } else {
+            mind.goals.remove(&executive::Goal::Socialize);
+        }
+        None
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Companioning {
+    Follow(AgentId),
+    Part(AgentId),
+}
+
+// Keeps companions together until they've had enough of each other.
+pub struct Companionship {
+    action: Cell<Option<Companioning>>,
+}
+impl Companionship {
+    pub fn new() -> Companionship {
+        Companionship {
+            action: Cell::new(None),
+        }
+    }
+}
+impl Daemon for Companionship {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        if !agent.health.borrow().awake {
+            return None;
+        }
+        let mind = agent.mind.borrow();
+        let (other, until) = mind.companion?;
+
+        let fond = *mind.opinions_on_others.get(&other).unwrap_or(&0.0) > 0.0;
+        if world.time > until || !fond || !world.agents[other].health.borrow().alive {
+            self.action.set(Some(Companioning::Part(other)));
+            Some(1.0)
+        } else if world.agents[other].location != agent.location {
+            self.action.set(Some(Companioning::Follow(other)));
+            Some(2.0)
+        } else {
+            None
+        }
+    }
+
+    fn events(&self, agent: &Agent, world: &World) -> Vec<Box<dyn Event>> {
+        match self.action.get() {
+            Some(Companioning::Follow(other)) => {
+                let target = world.agents[other].location;
+                let mut route = Vec::new();
+                vec![executive::travel(agent, world, &mut route, target)]
+            },
+            Some(Companioning::Part(other)) => vec![
+                Box::new(events::PartEvent { agent: agent.id, other: other }),
+            ],
+            None => vec![],
+        }
+    }
+}
+
+// Ends partnerships that have soured.
+pub struct Romance {
+    partner: Cell<Option<AgentId>>,
+}
+impl Romance {
+    pub fn new() -> Romance {
+        Romance {
+            partner: Cell::new(None),
+        }
+    }
+}
+impl Daemon for Romance {
+    fn step_simulation(&self, agent: &Agent, _: &World) -> Option<f64> {
+        if !agent.health.borrow().awake {
+            return None;
+        }
+        let mind = agent.mind.borrow();
+        let partner = mind.partner?;
+        self.partner.set(Some(partner));
+        if *mind.opinions_on_others.get(&partner).unwrap_or(&0.0) < 0.0 {
+            Some(1.0)
+        } else {
+            None
+        }
+    }
+
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
+        vec![
+            Box::new(events::BreakupEvent { agent: agent.id, other: self.partner.get().unwrap() })
+        ]
+    }
+}
+
+// Lets memories fade, and brings old ones back when the agent returns to
+// where they happened or sees someone who was there.
+pub struct Reminiscence {
+    memory: Cell<Option<MemoryId>>,
+}
+impl Reminiscence {
+    pub fn new() -> Reminiscence {
+        Reminiscence {
+            memory: Cell::new(None),
+        }
+    }
+}
+impl Daemon for Reminiscence {
+    fn step_simulation(&self, agent: &Agent, world: &World) -> Option<f64> {
+        let mut mind = agent.mind.borrow_mut();
+        for memory in mind.memories.iter_mut() {
+            memory.strength *= 0.998;
+        }
+        mind.memories.retain(|m| m.strength > 0.2);
+
+        // Storms are worth remembering when there's nowhere to hide.
+        let location = &world.locations[agent.location];
+        let stormy = world.weather != Weather::Clear && location.shelter.is_none();
+        let recent_storm = mind.memories.iter().any(|m| m.kind == MemoryKind::Storm && world.time - m.time < 12.0);
+        if stormy && !recent_storm && rand::thread_rng().gen_range(0, 6) == 0 {
+            let description = format!("the {} in the {}", world.weather.name(), location.name);
+            let id = mind.new_memory_id();
+            mind.memories.push(Memory::new(id, MemoryKind::Storm, world.time, location.id, Vec::new(), description, -1.0));
+        }
+
+        if !agent.health.borrow().awake || rand::thread_rng().gen_range(0, 20) != 0 {
+            return None;
+        }
+        let present = &location.agents;
+        let reminder = mind.memories.iter()
+            .filter(|m| world.time - m.time > 24.0 && world.time - m.last_recalled > 48.0)
+            .filter(|m| m.location == agent.location || m.people.iter().any(|p| present.contains(p)))
+            .max_by(|a, b| a.strength.partial_cmp(&b.strength).unwrap());
+        match reminder {
+            Some(memory) => {
+                self.memory.set(Some(memory.id));
+                Some((0.2 * memory.strength).min(1.0))
+            },
+            None => None,
+        }
+    }
+
+    fn events(&self, agent: &Agent, _: &World) -> Vec<Box<dyn Event>> {
+        let memory = self.memory.get().unwrap();
+        let description = agent.mind.borrow().memories.iter()
+            .find(|m| m.id == memory)
+            .map_or_else(String::new, |m| m.description.clone());
+        vec![
+            Box::new(events::RecallEvent { agent: agent.id, memory: memory, description: description })
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::tests::line_world;
+
+    #[test]
+    fn unsheltered_agents_get_through_a_spring_day_and_night() {
+        for weather in &[Weather::Clear, Weather::Rain, Weather::Snow] {
+            let mut world = line_world(1);
+            world.weather = *weather;
+            let agent = Agent::new(0);
+            for hour in 0..24 {
+                world.time = hour as f64;
+                assert!(WarmthTracker.step_simulation(&agent, &world).is_none(), "froze in the {}", weather.name());
+            }
+            assert!(agent.health.borrow().temperature > 35.0, "too cold after a day of {}", weather.name());
+        }
+    }
+}
@@ -1,6 +1,20 @@
-use super::{Event, World, AgentId, LocationId};
-use super::super::DummyEvent;
+use rand::Rng;
+use rand::seq::{IteratorRandom, SliceRandom};
+
+use std::collections::{HashMap, HashSet};
+
+use super::{Agent, Event, Item, World, AgentId, ItemId, LocationId, FAVOR_LIMIT};
+use super::super::{DummyEvent, WaterSource};
+use super::super::farming::CropStage;
+use super::super::wildlife::{CreatureId, Species};
 use super::executive;
+use super::ailments::{Ailment, AilmentKind};
+use super::personality::Personality;
+use super::relationships::{Courtship, Relationship};
+use super::beliefs::{Belief, Claim, Source};
+use super::memories::{Memory, MemoryId, MemoryKind};
+use super::professions::Profession;
+use super::super::items::{self, Category, ItemTypeId, ITEM_TYPES, RECIPES};
 
 #[derive(Copy, Clone)]
 pub struct MoveEvent {
@@ -11,17 +25,40 @@ pub struct MoveEvent {
 
 impl Event for MoveEvent {
     fn apply(&self, world: &mut World) {
-        let agent = &mut world.agents[self.agent];
+        if !world.agents[self.agent].health.borrow().alive {
+            return;
+        }
+        let fell = {
+            let agent = &mut world.agents[self.agent];
 
-        world.locations[self.start].agents.remove_item(&self.agent);
-        world.locations[self.end].agents.push(self.agent);
-        agent.location = self.end;
-        agent.events.push(Box::new(*self));
+            world.locations[self.start].agents.remove(&self.agent);
+            world.locations[self.end].agents.insert(self.agent);
+            agent.location = self.end;
+            agent.events.push(Box::new(*self));
+            agent.walk(self.start, self.end);
+            world.locations[self.end].visits += 1;
 
-        let mut mind = agent.mind.borrow_mut();
-        let cheer = mind.cheer;
-        let opinion = mind.opinions_on_places.entry(self.start).or_insert(0.0);
-        *opinion += cheer / 10.0
+            let mut mind = agent.mind.borrow_mut();
+            let cheer = mind.cheer;
+            let opinion = mind.opinions_on_places.entry(self.start).or_insert(0.0);
+            *opinion += cheer / 10.0;
+
+            // Reckless agents take more tumbles in the greenwood.
+            let mut rng = rand::thread_rng();
+            let risk = 0.002 * Personality::scale(agent.personality.bravery);
+            world.locations[self.end].name == "forest" && rng.gen::<f64>() < risk
+        };
+
+        observe(world, self.agent);
+
+        if fell {
+            let kind = if rand::thread_rng().gen_range(0, 5) == 0 {
+                AilmentKind::BrokenArm
+            } else {
+                AilmentKind::TwistedAnkle
+            };
+            AfflictEvent { agent: self.agent, kind: kind }.apply(world);
+        }
     }
 
     fn to_string(&self, world: &World) -> String {
@@ -40,15 +77,45 @@ pub struct PickupEvent {
 
 impl Event for PickupEvent {
     fn apply(&self, world: &mut World) {
+        let time = world.time;
         let location = &mut world.locations[self.location];
         let agent = &mut world.agents[self.agent];
 
         match location.items.remove_entry(&self.item) {
             Some((_, item)) => {
-                agent.events.push(Box::new(DummyEvent {
-                    agent: agent.id,
-                    message: format!("Picked up {}.", item.name).to_string(),
-                }));
+                if !agent.can_carry(&item) {
+                    agent.events.push(Box::new(DummyEvent {
+                        agent: agent.id,
+                        message: format!("Couldn't carry {} as well.", item.kind().with_article()).to_string(),
+                    }));
+                    location.items.insert(item.id, item);
+                    return;
+                }
+                if location.storehouse {
+                    // Taking from a storehouse is paid for out of the favor
+                    // the village owes.
+                    let favor = location.favors.entry(agent.id).or_insert(0.0);
+                    if *favor <= FAVOR_LIMIT {
+                        agent.events.push(Box::new(DummyEvent {
+                            agent: agent.id,
+                            message: "Was turned away from the storehouse for owing too much.".to_string(),
+                        }));
+                        location.items.insert(item.id, item);
+                        return;
+                    }
+                    *favor -= item.kind().value;
+                    agent.events.push(Box::new(DummyEvent {
+                        agent: agent.id,
+                        message: format!("Took {} from the storehouse.", item.kind().with_article()).to_string(),
+                    }));
+                } else {
+                    agent.events.push(Box::new(DummyEvent {
+                        agent: agent.id,
+                        message: format!("Picked up {}.", item.describe(agent.id)).to_string(),
+                    }));
+                }
+                let mut item = item;
+                item.picked_up = item.picked_up.or(Some(time));
                 agent.inventory.insert(item.id, item);
             },
             None => {
@@ -60,48 +127,473 @@ impl Event for PickupEvent {
         }
     }
 
-    fn to_string(&self, world: &World) -> String {
+    fn to_string(&self, _: &World) -> String {
         "Trying to pick something up.".to_string()
     }
 }
 
-pub struct EatEvent {
-    pub item: LocationId,
+pub struct DropEvent {
+    pub location: LocationId,
+    pub item: ItemId,
     pub agent: AgentId,
 }
 
-impl Event for EatEvent {
+impl Event for DropEvent {
     fn apply(&self, world: &mut World) {
+        let location = &mut world.locations[self.location];
         let agent = &mut world.agents[self.agent];
 
-        match agent.inventory.remove_entry(&self.item) {
-            Some((_, item)) => {
+        if let Some((_, item)) = agent.inventory.remove_entry(&self.item) {
+            agent.events.push(Box::new(DummyEvent {
+                agent: agent.id,
+                message: format!("Put down {}.", item.describe(agent.id)).to_string(),
+            }));
+            location.items.insert(item.id, item);
+        }
+    }
+
+    fn to_string(&self, _: &World) -> String {
+        "Putting something down.".to_string()
+    }
+}
+
+// Leaves all carried food at a location, usually home.
+pub struct StockpileEvent {
+    pub location: LocationId,
+    pub agent: AgentId,
+}
+
+impl Event for StockpileEvent {
+    fn apply(&self, world: &mut World) {
+        let location = &mut world.locations[self.location];
+        let agent = &mut world.agents[self.agent];
+
+        let food: Vec<ItemId> = agent.inventory.values()
+            .filter(|i| i.kind().is(Category::Food))
+            .map(|i| i.id)
+            .collect();
+        let mut counts: HashMap<ItemTypeId, usize> = HashMap::new();
+        for id in food {
+            let item = agent.inventory.remove(&id).unwrap();
+            *counts.entry(item.kind).or_insert(0) += 1;
+            location.items.insert(item.id, item);
+        }
+
+        if !counts.is_empty() {
+            let mut kinds: Vec<&ItemTypeId> = counts.keys().collect();
+            kinds.sort();
+            let stored: Vec<String> = kinds.iter().map(|k| ITEM_TYPES[**k].count(counts[k])).collect();
+            let place = if self.location == agent.home { "at home" } else { "here" };
+            agent.events.push(Box::new(DummyEvent {
+                agent: agent.id,
+                message: format!("Stored {} {}.", stored.join(", "), place).to_string(),
+            }));
+        }
+        *world.metrics.entry("stockpile").or_insert(0) += 1;
+    }
+
+    fn to_string(&self, _: &World) -> String {
+        "Storing food.".to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct CraftEvent {
+    pub agent: AgentId,
+    pub output: ItemTypeId,
+}
+
+impl Event for CraftEvent {
+    fn apply(&self, world: &mut World) {
+        let id = world.new_item_id();
+        let agent = &mut world.agents[self.agent];
+        let recipe = match RECIPES.iter().find(|r| r.output == self.output) {
+            Some(recipe) => recipe,
+            None => return,
+        };
+
+        for (kind, count) in recipe.inputs {
+            let used: Vec<ItemId> = agent.inventory.values()
+                .filter(|i| i.kind == *kind)
+                .map(|i| i.id)
+                .take(*count)
+                .collect();
+            if used.len() < *count {
                 agent.events.push(Box::new(DummyEvent {
                     agent: agent.id,
-                    message: format!("Ate {}.", item.name).to_string(),
+                    message: format!("Didn't have enough {} to finish {}.", ITEM_TYPES[*kind].plural, ITEM_TYPES[self.output].with_article()).to_string(),
                 }));
-                let mut health = agent.health.borrow_mut();
-                health.hunger = (health.hunger - item.food_value).max(0.0);
+                return;
+            }
+            for id in used {
+                agent.inventory.remove(&id);
+            }
+        }
 
-                if health.hunger < 5.0 {
-                    let mut mind = agent.mind.borrow_mut();
-                    mind.goals.remove(&executive::Goal::FindFood);
-                }
-            },
-            None => {
+        let mut item = Item::new(id, self.output);
+        item.crafted_by = Some(agent.id);
+        agent.inventory.insert(item.id, item);
+        agent.events.push(Box::new(*self));
+
+        *world.metrics.entry("craft").or_insert(0) += 1;
+    }
+
+    fn to_string(&self, _: &World) -> String {
+        format!("Made {} of their own.", ITEM_TYPES[self.output].with_article()).to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct FishEvent {
+    pub agent: AgentId,
+    pub location: LocationId,
+}
+
+impl Event for FishEvent {
+    fn apply(&self, world: &mut World) {
+        if world.locations[self.location].water != Some(WaterSource::Stream) {
+            return;
+        }
+        let id = world.new_item_id();
+        let agent = &mut world.agents[self.agent];
+        let rod = match agent.inventory.values().find(|i| i.kind == items::FISHING_ROD) {
+            Some(rod) => rod.describe(agent.id),
+            None => return,
+        };
+
+        if rand::thread_rng().gen_range(0, 3) == 0 {
+            let mut fish = Item::new(id, items::FISH);
+            fish.picked_up = Some(world.time);
+            let message = if agent.can_carry(&fish) {
+                agent.inventory.insert(fish.id, fish);
+                format!("Caught a fish with {}.", rod)
+            } else {
+                world.locations[self.location].items.insert(fish.id, fish);
+                format!("Caught a fish with {}, but had no room to carry it.", rod)
+            };
+            agent.events.push(Box::new(DummyEvent {
+                agent: agent.id,
+                message: message,
+            }));
+        } else {
+            agent.events.push(Box::new(DummyEvent {
+                agent: agent.id,
+                message: "Fished for an hour without a bite.".to_string(),
+            }));
+        }
+    }
+
+    fn to_string(&self, _: &World) -> String {
+        "Fishing.".to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct WorkEvent {
+    pub agent: AgentId,
+    pub location: LocationId,
+    pub profession: Profession,
+}
+
+impl Event for WorkEvent {
+    fn apply(&self, world: &mut World) {
+        let time = world.time;
+
+        let location = &mut world.locations[self.location];
+        for (kind, count) in self.profession.inputs() {
+            let available = location.items.values().filter(|i| i.kind == *kind).count();
+            if available < *count {
+                let agent = &mut world.agents[self.agent];
                 agent.events.push(Box::new(DummyEvent {
                     agent: agent.id,
-                    message: "Tried to eat something up but it wasn't there".to_string(),
+                    message: format!("Couldn't work for want of {}.", ITEM_TYPES[*kind].plural).to_string(),
                 }));
+                return;
             }
         }
+        for (kind, count) in self.profession.inputs() {
+            let used: Vec<ItemId> = location.items.values().filter(|i| i.kind == *kind).map(|i| i.id).take(*count).collect();
+            for id in used {
+                location.items.remove(&id);
+            }
+        }
+
+        let mut made = Vec::new();
+        if self.profession == Profession::Trader {
+            // Traders come home with whatever the other village can spare.
+            let goods: Vec<ItemId> = location.items.values()
+                .filter(|i| !i.kind().is(Category::Tool) && !i.spoiled(time))
+                .map(|i| i.id)
+                .take(3)
+                .collect();
+            for id in goods {
+                made.push(location.items.remove(&id).unwrap());
+            }
+        } else {
+            for (kind, count) in self.profession.outputs() {
+                for _ in 0..*count {
+                    let mut item = Item::new(world.new_item_id(), *kind);
+                    item.picked_up = Some(time);
+                    made.push(item);
+                }
+            }
+        }
+
+        // Whatever can't be carried is left where it was made. Traders pay
+        // for what they take with favor at the other village's storehouse.
+        let day = world.day();
+        let agent = &mut world.agents[self.agent];
+        let location = &mut world.locations[self.location];
+        agent.events.push(Box::new(*self));
+        agent.mind.borrow_mut().last_workday = day;
+        for item in made {
+            let favor = location.favors.entry(agent.id).or_insert(0.0);
+            let affordable = self.profession != Profession::Trader || *favor - item.kind().value > FAVOR_LIMIT;
+            if agent.can_carry(&item) && affordable {
+                if self.profession == Profession::Trader {
+                    *favor -= item.kind().value;
+                }
+                agent.inventory.insert(item.id, item);
+            } else {
+                location.items.insert(item.id, item);
+            }
+        }
+        *world.metrics.entry("work").or_insert(0) += 1;
     }
 
-    fn to_string(&self, world: &World) -> String {
+    fn to_string(&self, _: &World) -> String {
+        self.profession.work_description().to_string()
+    }
+}
+
+// Hands over the day's goods to the storehouse in exchange for favor.
+#[derive(Copy, Clone)]
+pub struct DepositEvent {
+    pub agent: AgentId,
+    pub location: LocationId,
+}
+
+impl Event for DepositEvent {
+    fn apply(&self, world: &mut World) {
+        let day = world.day();
+        let location = &mut world.locations[self.location];
+        let agent = &mut world.agents[self.agent];
+
+        let goods: Vec<ItemId> = agent.inventory.values()
+            .filter(|i| i.kind().is(Category::Food) || i.kind().is(Category::Material))
+            .filter(|i| i.crafted_by.is_none())
+            .map(|i| i.id)
+            .collect();
+        let mut counts: HashMap<ItemTypeId, usize> = HashMap::new();
+        let mut value = 0.0;
+        for id in goods {
+            let item = agent.inventory.remove(&id).unwrap();
+            *counts.entry(item.kind).or_insert(0) += 1;
+            value += item.kind().value;
+            location.items.insert(item.id, item);
+        }
+        *location.favors.entry(agent.id).or_insert(0.0) += value;
+
+        agent.mind.borrow_mut().last_workday = day;
+        if !counts.is_empty() {
+            let mut kinds: Vec<&ItemTypeId> = counts.keys().collect();
+            kinds.sort();
+            let brought: Vec<String> = kinds.iter().map(|k| ITEM_TYPES[**k].count(counts[k])).collect();
+            agent.events.push(Box::new(DummyEvent {
+                agent: agent.id,
+                message: format!("Brought {} to the storehouse.", brought.join(", ")).to_string(),
+            }));
+        }
+    }
+
+    fn to_string(&self, _: &World) -> String {
+        "Visiting the storehouse.".to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct FarmEvent {
+    pub agent: AgentId,
+    pub location: LocationId,
+}
+
+impl Event for FarmEvent {
+    fn apply(&self, world: &mut World) {
+        let season = world.season();
+        let time = world.time;
+        let (message, harvest) = {
+            let field = match world.locations[self.location].field.as_mut() {
+                Some(field) => field,
+                None => return,
+            };
+            match field.stage {
+                CropStage::Fallow if season.planting() => {
+                    field.sow();
+                    (format!("Sowed a field with {}.", field.crop_name()), None)
+                },
+                CropStage::Fallow => ("Found nothing to do in the fallow field.".to_string(), None),
+                CropStage::Sown | CropStage::Sprouting => {
+                    field.since_tended = 0.0;
+                    (format!("Weeded and watered the {}.", field.crop_name()), None)
+                },
+                CropStage::Ripe => {
+                    let count = field.harvest_size();
+                    field.stage = CropStage::Fallow;
+                    (format!("Harvested {}.", ITEM_TYPES[field.crop].count(count)), Some((field.crop, count)))
+                },
+                CropStage::Failed => {
+                    field.stage = CropStage::Fallow;
+                    (format!("Cleared away the ruined {}.", field.crop_name()), None)
+                },
+            }
+        };
+
+        let mut crops = Vec::new();
+        if let Some((kind, count)) = harvest {
+            for _ in 0..count {
+                let mut item = Item::new(world.new_item_id(), kind);
+                item.picked_up = Some(time);
+                crops.push(item);
+            }
+            *world.metrics.entry("harvest").or_insert(0) += 1;
+        }
+
+        let agent = &mut world.agents[self.agent];
+        agent.events.push(Box::new(DummyEvent {
+            agent: agent.id,
+            message: message.to_string(),
+        }));
+        // Whatever can't be carried is left in the field.
+        for item in crops {
+            if agent.can_carry(&item) {
+                agent.inventory.insert(item.id, item);
+            } else {
+                world.locations[self.location].items.insert(item.id, item);
+            }
+        }
+    }
+
+    fn to_string(&self, _: &World) -> String {
+        "Working in the fields.".to_string()
+    }
+}
+
+pub struct EatEvent {
+    pub item: LocationId,
+    pub agent: AgentId,
+}
+
+impl Event for EatEvent {
+    fn apply(&self, world: &mut World) {
+        let time = world.time;
+        let ailment = {
+            let agent = &mut world.agents[self.agent];
+
+            match agent.inventory.remove_entry(&self.item) {
+                Some((_, item)) => {
+                    let spoiled = item.spoiled(time);
+                    agent.events.push(Box::new(DummyEvent {
+                        agent: agent.id,
+                        message: if spoiled {
+                            format!("Ate a rotten {}.", item.kind().name).to_string()
+                        } else {
+                            format!("Ate {}.", item.kind().with_article()).to_string()
+                        },
+                    }));
+                    let mut health = agent.health.borrow_mut();
+                    health.hunger = (health.hunger - item.kind().food_value).max(0.0);
+
+                    let mut mind = agent.mind.borrow_mut();
+                    if health.hunger < 5.0 {
+                        mind.goals.remove(&executive::Goal::FindFood);
+                    }
+
+                    let mut rng = rand::thread_rng();
+                    let opinion = mind.opinions_on_foods.entry(item.kind).or_insert(0.0);
+                    if item.kind().poisonous {
+                        // Once bitten, twice shy.
+                        *opinion = -1.0;
+                        Some(AilmentKind::Poisoning)
+                    } else {
+                        *opinion += 0.1;
+                        if (spoiled && rng.gen_range(0, 2) == 0) || rng.gen_range(0, 200) == 0 {
+                            Some(AilmentKind::StomachAche)
+                        } else {
+                            None
+                        }
+                    }
+                },
+                None => {
+                    agent.events.push(Box::new(DummyEvent {
+                        agent: agent.id,
+                        message: "Tried to eat something up but it wasn't there".to_string(),
+                    }));
+                    None
+                }
+            }
+        };
+
+        if let Some(kind) = ailment {
+            AfflictEvent { agent: self.agent, kind: kind }.apply(world);
+        }
+    }
+
+    fn to_string(&self, _: &World) -> String {
         "Trying to eat something.".to_string()
     }
 }
 
+#[derive(Copy, Clone)]
+pub struct DrinkEvent {
+    pub location: LocationId,
+    pub agent: AgentId,
+}
+
+impl Event for DrinkEvent {
+    fn apply(&self, world: &mut World) {
+        let agent = &mut world.agents[self.agent];
+        agent.events.push(Box::new(*self));
+
+        let mut health = agent.health.borrow_mut();
+        health.thirst = 0.0;
+
+        let mut mind = agent.mind.borrow_mut();
+        mind.goals.remove(&executive::Goal::Drink);
+
+        *world.metrics.entry("drink").or_insert(0) += 1;
+    }
+
+    fn to_string(&self, world: &World) -> String {
+        match world.locations[self.location].water {
+            Some(water) => format!("Drank from the {}.", water.name()).to_string(),
+            None => "Drank some water.".to_string(),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ShelterEvent {
+    pub location: LocationId,
+    pub agent: AgentId,
+}
+
+impl Event for ShelterEvent {
+    fn apply(&self, world: &mut World) {
+        let agent = &mut world.agents[self.agent];
+        agent.events.push(Box::new(*self));
+
+        let mut mind = agent.mind.borrow_mut();
+        mind.goals.remove(&executive::Goal::KeepWarm);
+    }
+
+    fn to_string(&self, world: &World) -> String {
+        match world.locations[self.location].shelter {
+            Some(shelter) => format!("Took shelter from the cold in a {}.", shelter.name()).to_string(),
+            None => "Huddled against the cold.".to_string(),
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct NapEvent {
     pub agent: AgentId,
@@ -109,12 +601,12 @@ pub struct NapEvent {
 impl Event for NapEvent {
     fn apply(&self, world: &mut World) {
         let agent = &mut world.agents[self.agent];
-        agent.events.push(Box::new(self.clone()));
+        agent.events.push(Box::new(*self));
         let mut health = agent.health.borrow_mut();
         health.awake = false;
     }
-    fn to_string(&self, world: &World) -> String {
-        format!("Went to sleep.").to_string()
+    fn to_string(&self, _: &World) -> String {
+        "Went to sleep.".to_string()
     }
 }
 
@@ -125,12 +617,12 @@ pub struct WakeEvent {
 impl Event for WakeEvent {
     fn apply(&self, world: &mut World) {
         let agent = &mut world.agents[self.agent];
-        agent.events.push(Box::new(self.clone()));
+        agent.events.push(Box::new(*self));
         let mut health = agent.health.borrow_mut();
         health.awake = true;
     }
-    fn to_string(&self, world: &World) -> String {
-        format!("Woke up.").to_string()
+    fn to_string(&self, _: &World) -> String {
+        "Woke up.".to_string()
     }
 }
 
@@ -143,7 +635,7 @@ impl Event for MeetEvent {
     fn apply(&self, world: &mut World) {
         {
             let agent = &mut world.agents[self.agent];
-            agent.events.push(Box::new(self.clone()));
+            agent.events.push(Box::new(*self));
             let mut mind = agent.mind.borrow_mut();
             let cheer = mind.cheer;
             let o = mind.opinions_on_others.entry(self.other).or_insert(0.0);
@@ -157,6 +649,20 @@ impl Event for MeetEvent {
             let o = mind.opinions_on_others.entry(self.agent).or_insert(0.0);
             *o += cheer;
         }
+
+        // Strangers go on what the village says about each other.
+        let agent_reputation = world.agents[self.agent].reputation(world);
+        let other_reputation = world.agents[self.other].reputation(world);
+        adjust_opinion(world, self.agent, self.other, 0.5 * other_reputation);
+        adjust_opinion(world, self.other, self.agent, 0.5 * agent_reputation);
+
+        adjust_relationship(world, self.agent, self.other, |_| ());
+        adjust_relationship(world, self.other, self.agent, |_| ());
+
+        share_food_warnings(world, self.agent, self.other);
+        share_food_warnings(world, self.other, self.agent);
+        found_alive(world, self.agent, self.other);
+        found_alive(world, self.other, self.agent);
     }
     fn to_string(&self, world: &World) -> String {
         let other = &world.agents[self.other];
@@ -164,6 +670,30 @@ impl Event for MeetEvent {
     }
 }
 
+// Agents warn each other about anything that has made them sick.
+fn share_food_warnings(world: &mut World, speaker: AgentId, listener: AgentId) {
+    let warnings: Vec<ItemTypeId> = {
+        let speaker_mind = world.agents[speaker].mind.borrow();
+        let listener_mind = world.agents[listener].mind.borrow();
+        speaker_mind.opinions_on_foods.iter()
+            .filter(|(_, o)| **o < 0.0)
+            .filter(|(food, _)| *listener_mind.opinions_on_foods.get(*food).unwrap_or(&0.0) >= 0.0)
+            .map(|(food, _)| *food)
+            .collect()
+    };
+
+    let speaker_name = world.agents[speaker].name.clone();
+    let agent = &mut world.agents[listener];
+    let mut mind = agent.mind.borrow_mut();
+    for food in warnings {
+        agent.events.push(Box::new(DummyEvent {
+            agent: agent.id,
+            message: format!("Learned from {} that {} are poisonous.", speaker_name, ITEM_TYPES[food].plural).to_string(),
+        }));
+        mind.opinions_on_foods.insert(food, -1.0);
+    }
+}
+
 #[derive(Copy, Clone)]
 pub struct DieEvent {
     pub agent: AgentId,
@@ -171,12 +701,34 @@ pub struct DieEvent {
 impl Event for DieEvent {
     fn apply(&self, world: &mut World) {
         let agent = &mut world.agents[self.agent];
-        agent.events.push(Box::new(self.clone()));
-        let mut health = agent.health.borrow_mut();
-        health.alive = false
+        if !agent.health.borrow().alive {
+            return;
+        }
+        agent.events.push(Box::new(*self));
+        agent.health.borrow_mut().alive = false;
+        // The dead no longer count as anyone's company.
+        let location = agent.location;
+        world.locations[location].agents.remove(&self.agent);
+
+        let time = world.time;
+        for a in &world.locations[location].agents {
+            let mut mind = world.agents[*a].mind.borrow_mut();
+            mind.beliefs.insert(Claim::Dead { agent: self.agent }, Belief::witnessed(time));
+        }
+        let name = world.agents[self.agent].name.clone();
+        for a in world.locations[location].agents.clone() {
+            remember(world, a, format!("watching {} die", name), -4.0);
+        }
+
+        let partner = world.agents[self.agent].mind.borrow_mut().partner.take();
+        if let Some(partner) = partner {
+            world.agents[partner].mind.borrow_mut().partner = None;
+            record(world, partner, format!("Grieved for {}.", name));
+            remember(world, partner, format!("losing {}", name), -4.0);
+        }
     }
-    fn to_string(&self, world: &World) -> String {
-        format!("Died.").to_string()
+    fn to_string(&self, _: &World) -> String {
+        "Died.".to_string()
     }
 }
 
@@ -187,7 +739,7 @@ pub struct DefecateEvent {
 impl Event for DefecateEvent {
     fn apply(&self, world: &mut World) {
         let agent = &mut world.agents[self.agent];
-        agent.events.push(Box::new(self.clone()));
+        agent.events.push(Box::new(*self));
 
         let mut health = agent.health.borrow_mut();
         health.poop = 0.0;
@@ -198,6 +750,879 @@ impl Event for DefecateEvent {
         *world.metrics.entry("shit").or_insert(0) += 1;
     }
     fn to_string(&self, _: &World) -> String {
-        format!("Took a shit.").to_string()
+        "Took a shit.".to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct AfflictEvent {
+    pub agent: AgentId,
+    pub kind: AilmentKind,
+}
+impl Event for AfflictEvent {
+    fn apply(&self, world: &mut World) {
+        let agent = &mut world.agents[self.agent];
+        agent.events.push(Box::new(*self));
+        let mut health = agent.health.borrow_mut();
+        health.ailments.push(Ailment::new(self.kind));
+        drop(health);
+        remember(world, self.agent, format!("the {}", self.kind.name()), -1.5);
+
+        *world.metrics.entry("ailment").or_insert(0) += 1;
+    }
+    fn to_string(&self, _: &World) -> String {
+        self.kind.onset().to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct RecoverEvent {
+    pub agent: AgentId,
+    pub kind: AilmentKind,
+}
+impl Event for RecoverEvent {
+    fn apply(&self, world: &mut World) {
+        let agent = &mut world.agents[self.agent];
+        agent.events.push(Box::new(*self));
+        let mut health = agent.health.borrow_mut();
+        if let Some(i) = health.ailments.iter().position(|a| a.kind == self.kind) {
+            health.ailments.remove(i);
+        }
+    }
+    fn to_string(&self, _: &World) -> String {
+        format!("Recovered from a {}.", self.kind.name()).to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct CareEvent {
+    pub agent: AgentId,
+    pub patient: AgentId,
+}
+impl Event for CareEvent {
+    fn apply(&self, world: &mut World) {
+        if world.agents[self.agent].location != world.agents[self.patient].location {
+            return;
+        }
+        world.agents[self.agent].events.push(Box::new(*self));
+
+        let carer_name = world.agents[self.agent].name.clone();
+        let patient = &mut world.agents[self.patient];
+        patient.events.push(Box::new(DummyEvent {
+            agent: patient.id,
+            message: format!("Was cared for by {}.", carer_name).to_string(),
+        }));
+
+        let mut health = patient.health.borrow_mut();
+        for ailment in health.ailments.iter_mut() {
+            ailment.remaining -= 4.0;
+        }
+
+        let mut mind = patient.mind.borrow_mut();
+        let o = mind.opinions_on_others.entry(self.agent).or_insert(0.0);
+        *o += 1.0;
+        mind.relationships.entry(self.agent).or_insert_with(Relationship::new).trust += 1.0;
+    }
+    fn to_string(&self, world: &World) -> String {
+        let patient = &world.agents[self.patient];
+        format!("Tended to {}.", patient.name).to_string()
+    }
+}
+
+fn adjust_opinion(world: &World, agent: AgentId, other: AgentId, amount: f64) {
+    let mut mind = world.agents[agent].mind.borrow_mut();
+    let o = mind.opinions_on_others.entry(other).or_insert(0.0);
+    *o += amount;
+}
+
+fn adjust_relationship<F: FnOnce(&mut Relationship)>(world: &World, agent: AgentId, other: AgentId, change: F) {
+    let mut mind = world.agents[agent].mind.borrow_mut();
+    change(mind.relationships.entry(other).or_insert_with(Relationship::new));
+}
+
+#[derive(Copy, Clone)]
+pub struct GiftEvent {
+    pub agent: AgentId,
+    pub other: AgentId,
+    pub item: ItemId,
+}
+impl Event for GiftEvent {
+    fn apply(&self, world: &mut World) {
+        if world.agents[self.agent].location != world.agents[self.other].location {
+            return;
+        }
+        let fits = match world.agents[self.agent].inventory.get(&self.item) {
+            Some(item) => world.agents[self.other].can_carry(item),
+            None => false,
+        };
+        if !fits {
+            return;
+        }
+        let item = world.agents[self.agent].inventory.remove(&self.item).unwrap();
+        let description = item.kind().with_article();
+        let value = item.kind().value;
+        let kind = item.kind;
+
+        let giver_name = world.agents[self.agent].name.clone();
+        let other = &mut world.agents[self.other];
+        other.events.push(Box::new(DummyEvent {
+            agent: other.id,
+            message: format!("Was given {} by {}.", description, giver_name).to_string(),
+        }));
+        other.inventory.insert(item.id, item);
+
+        let receiver_name = world.agents[self.other].name.clone();
+        let agent = &mut world.agents[self.agent];
+        agent.events.push(Box::new(DummyEvent {
+            agent: agent.id,
+            message: format!("Gave {} to {}.", description, receiver_name).to_string(),
+        }));
+
+        adjust_opinion(world, self.other, self.agent, 1.0 + value);
+        adjust_opinion(world, self.agent, self.other, 0.2);
+        adjust_relationship(world, self.other, self.agent, |r| r.trust += 0.5);
+        remember(world, self.other, format!("the {} {} gave them", ITEM_TYPES[kind].name, giver_name), 1.0);
+        *world.metrics.entry("gift").or_insert(0) += 1;
+    }
+    fn to_string(&self, _: &World) -> String {
+        "Giving a gift.".to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct TradeEvent {
+    pub agent: AgentId,
+    pub other: AgentId,
+    pub give: ItemId,
+    pub receive: ItemId,
+}
+impl Event for TradeEvent {
+    fn apply(&self, world: &mut World) {
+        if world.agents[self.agent].location != world.agents[self.other].location {
+            return;
+        }
+        let ready = match (world.agents[self.agent].inventory.get(&self.give), world.agents[self.other].inventory.get(&self.receive)) {
+            (Some(give), Some(receive)) => world.agents[self.other].can_carry(give) && world.agents[self.agent].can_carry(receive),
+            _ => false,
+        };
+        if !ready {
+            return;
+        }
+        let give = world.agents[self.agent].inventory.remove(&self.give).unwrap();
+        let receive = world.agents[self.other].inventory.remove(&self.receive).unwrap();
+
+        let agent_name = world.agents[self.agent].name.clone();
+        let other_name = world.agents[self.other].name.clone();
+        let gave = give.describe(self.agent);
+        let got = receive.kind().with_article();
+
+        let agent = &mut world.agents[self.agent];
+        agent.events.push(Box::new(DummyEvent {
+            agent: agent.id,
+            message: format!("Traded {} to {} for {}.", gave, other_name, got).to_string(),
+        }));
+        agent.inventory.insert(receive.id, receive);
+
+        let other = &mut world.agents[self.other];
+        other.events.push(Box::new(DummyEvent {
+            agent: other.id,
+            message: format!("Traded {} to {} for {}.", got, agent_name, give.kind().with_article()).to_string(),
+        }));
+        other.inventory.insert(give.id, give);
+
+        adjust_opinion(world, self.agent, self.other, 0.2);
+        adjust_opinion(world, self.other, self.agent, 0.2);
+        adjust_relationship(world, self.agent, self.other, |r| r.trust += 0.2);
+        adjust_relationship(world, self.other, self.agent, |r| r.trust += 0.2);
+        *world.metrics.entry("trade").or_insert(0) += 1;
+    }
+    fn to_string(&self, _: &World) -> String {
+        "Trading.".to_string()
+    }
+}
+
+// Kills a creature and leaves its meat with the agent, or on the ground if
+// they can't carry it all.
+fn butcher(world: &mut World, agent: AgentId, creature: CreatureId) -> usize {
+    let time = world.time;
+    let (location, species) = {
+        let creature = &mut world.creatures[creature];
+        creature.alive = false;
+        (creature.location, creature.species)
+    };
+    world.locations[location].creatures.remove(&creature);
+
+    for _ in 0..species.meat() {
+        let mut meat = Item::new(world.new_item_id(), items::MEAT);
+        meat.picked_up = Some(time);
+        let agent = &mut world.agents[agent];
+        if agent.can_carry(&meat) {
+            agent.inventory.insert(meat.id, meat);
+        } else {
+            world.locations[location].items.insert(meat.id, meat);
+        }
+    }
+    species.meat()
+}
+
+#[derive(Copy, Clone)]
+pub struct HuntEvent {
+    pub agent: AgentId,
+    pub creature: CreatureId,
+}
+impl Event for HuntEvent {
+    fn apply(&self, world: &mut World) {
+        let (species, here) = {
+            let creature = &world.creatures[self.creature];
+            (creature.species, creature.alive && creature.location == world.agents[self.agent].location)
+        };
+        if !here {
+            return;
+        }
+
+        let agent = &world.agents[self.agent];
+        let armed = if agent.has(items::KNIFE) { 2.0 } else { 1.0 };
+        let chance = species.catch_chance() * armed * Personality::scale(agent.personality.bravery);
+        if rand::thread_rng().gen::<f64>() < chance {
+            let meat = butcher(world, self.agent, self.creature);
+            let agent = &mut world.agents[self.agent];
+            agent.events.push(Box::new(DummyEvent {
+                agent: agent.id,
+                message: format!("Killed a {} and took {} from it.", species.name(), ITEM_TYPES[items::MEAT].count(meat)).to_string(),
+            }));
+            *world.metrics.entry("kill").or_insert(0) += 1;
+        } else {
+            let agent = &mut world.agents[self.agent];
+            agent.events.push(Box::new(DummyEvent {
+                agent: agent.id,
+                message: format!("Stalked a {} but it got away.", species.name()).to_string(),
+            }));
+        }
+    }
+    fn to_string(&self, _: &World) -> String {
+        "Hunting.".to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct AttackEvent {
+    pub creature: CreatureId,
+    pub agent: AgentId,
+}
+impl Event for AttackEvent {
+    fn apply(&self, world: &mut World) {
+        let (species, location) = {
+            let creature = &world.creatures[self.creature];
+            (creature.species, creature.location)
+        };
+        let here = world.creatures[self.creature].alive && world.agents[self.agent].location == location;
+        if !here || !world.agents[self.agent].health.borrow().alive {
+            return;
+        }
+
+        {
+            let agent = &mut world.agents[self.agent];
+            agent.events.push(Box::new(DummyEvent {
+                agent: agent.id,
+                message: format!("Was attacked by a {}!", species.name()).to_string(),
+            }));
+            let mut mind = agent.mind.borrow_mut();
+            let opinion = mind.opinions_on_places.entry(location).or_insert(0.0);
+            *opinion -= 2.0;
+        }
+        remember(world, self.agent, format!("the {} attack", species.name()), -3.0);
+        *world.metrics.entry("attack").or_insert(0) += 1;
+
+        // The brave stand their ground, especially with a blade in hand.
+        let mut rng = rand::thread_rng();
+        let agent = &world.agents[self.agent];
+        let armed = if agent.has(items::KNIFE) { 2.0 } else { 1.0 };
+        let courage = agent.personality.bravery * 0.3 * armed;
+        if rng.gen::<f64>() < courage {
+            if rng.gen_range(0, 3) == 0 {
+                let meat = butcher(world, self.agent, self.creature);
+                let agent = &mut world.agents[self.agent];
+                agent.events.push(Box::new(DummyEvent {
+                    agent: agent.id,
+                    message: format!("Fought back and killed the {}, taking {} from it.", species.name(), ITEM_TYPES[items::MEAT].count(meat)).to_string(),
+                }));
+            } else {
+                let agent = &mut world.agents[self.agent];
+                agent.events.push(Box::new(DummyEvent {
+                    agent: agent.id,
+                    message: format!("Fought the {} off.", species.name()).to_string(),
+                }));
+            }
+        } else {
+            let kind = match species {
+                Species::Bear => AilmentKind::Mauling,
+                _ => AilmentKind::Bite,
+            };
+            AfflictEvent { agent: self.agent, kind: kind }.apply(world);
+        }
+    }
+    fn to_string(&self, _: &World) -> String {
+        "Attacked.".to_string()
+    }
+}
+
+// Anyone who sees someone start trouble thinks a little less of them.
+fn witnessed(world: &World, culprit: AgentId, victim: AgentId, amount: f64) {
+    let location = world.agents[culprit].location;
+    for a in &world.locations[location].agents {
+        if *a != culprit && *a != victim {
+            adjust_opinion(world, *a, culprit, -amount);
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ArgueEvent {
+    pub agent: AgentId,
+    pub other: AgentId,
+}
+impl Event for ArgueEvent {
+    fn apply(&self, world: &mut World) {
+        let agent_name = world.agents[self.agent].name.clone();
+        world.agents[self.agent].events.push(Box::new(*self));
+        let other = &mut world.agents[self.other];
+        other.events.push(Box::new(DummyEvent {
+            agent: other.id,
+            message: format!("Was shouted at by {}.", agent_name).to_string(),
+        }));
+
+        adjust_opinion(world, self.agent, self.other, -0.5);
+        adjust_opinion(world, self.other, self.agent, -0.5);
+        world.agents[self.other].mind.borrow_mut().agitation += 0.5;
+        witnessed(world, self.agent, self.other, 0.2);
+        *world.metrics.entry("argument").or_insert(0) += 1;
+    }
+    fn to_string(&self, world: &World) -> String {
+        format!("Argued bitterly with {}.", world.agents[self.other].name).to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct StealEvent {
+    pub agent: AgentId,
+    pub other: AgentId,
+    pub item: ItemId,
+}
+impl Event for StealEvent {
+    fn apply(&self, world: &mut World) {
+        if !face_to_face(world, self.agent, self.other) {
+            return;
+        }
+        match world.agents[self.other].inventory.get(&self.item) {
+            Some(item) if world.agents[self.agent].can_carry(item) => (),
+            _ => return,
+        }
+        let agent_name = world.agents[self.agent].name.clone();
+        let other_name = world.agents[self.other].name.clone();
+
+        // Sleeping marks are easy; wakeful ones notice half the time.
+        let noticed = world.agents[self.other].health.borrow().awake && rand::thread_rng().gen_range(0, 2) == 0;
+        if noticed {
+            world.agents[self.agent].events.push(Box::new(DummyEvent {
+                agent: self.agent,
+                message: format!("Was caught trying to steal from {}.", other_name).to_string(),
+            }));
+            world.agents[self.other].events.push(Box::new(DummyEvent {
+                agent: self.other,
+                message: format!("Caught {} trying to steal from them.", agent_name).to_string(),
+            }));
+            adjust_opinion(world, self.other, self.agent, -2.0);
+            adjust_relationship(world, self.other, self.agent, |r| r.trust -= 2.0);
+            witnessed(world, self.agent, self.other, 1.0);
+            return;
+        }
+
+        let item = world.agents[self.other].inventory.remove(&self.item).unwrap();
+        let description = item.kind().with_article();
+        world.agents[self.agent].inventory.insert(item.id, item);
+        world.agents[self.agent].events.push(Box::new(DummyEvent {
+            agent: self.agent,
+            message: format!("Stole {} from {}.", description, other_name).to_string(),
+        }));
+        *world.metrics.entry("theft").or_insert(0) += 1;
+    }
+    fn to_string(&self, _: &World) -> String {
+        "Stealing.".to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct FightEvent {
+    pub agent: AgentId,
+    pub other: AgentId,
+}
+impl Event for FightEvent {
+    fn apply(&self, world: &mut World) {
+        if !face_to_face(world, self.agent, self.other) {
+            return;
+        }
+        let mut rng = rand::thread_rng();
+        let mut strength = |a: &Agent| {
+            let armed = if a.has(items::KNIFE) { 0.5 } else { 0.0 };
+            a.personality.bravery + armed - a.health.borrow().pain * 0.05 + rng.gen_range(0.0, 1.0)
+        };
+        let agent_strength = strength(&world.agents[self.agent]);
+        let other_strength = strength(&world.agents[self.other]);
+        let (winner, loser) = if agent_strength >= other_strength {
+            (self.agent, self.other)
+        } else {
+            (self.other, self.agent)
+        };
+        let winner_name = world.agents[winner].name.clone();
+        let loser_name = world.agents[loser].name.clone();
+
+        let agent_name = world.agents[self.agent].name.clone();
+        world.agents[self.agent].events.push(Box::new(*self));
+        world.agents[self.other].events.push(Box::new(DummyEvent {
+            agent: self.other,
+            message: format!("Was set upon by {}.", agent_name).to_string(),
+        }));
+        world.agents[winner].events.push(Box::new(DummyEvent {
+            agent: winner,
+            message: format!("Beat {} in the fight.", loser_name).to_string(),
+        }));
+        world.agents[loser].events.push(Box::new(DummyEvent {
+            agent: loser,
+            message: format!("Lost the fight to {}.", winner_name).to_string(),
+        }));
+
+        world.agents[winner].health.borrow_mut().pain += 0.5;
+        let injury = if rng.gen_range(0, 10) == 0 { AilmentKind::BrokenArm } else { AilmentKind::Bruises };
+        AfflictEvent { agent: loser, kind: injury }.apply(world);
+
+        adjust_opinion(world, self.agent, self.other, -1.0);
+        adjust_opinion(world, self.other, self.agent, -1.0);
+        adjust_relationship(world, self.other, self.agent, |r| r.trust -= 1.0);
+        adjust_relationship(world, loser, winner, |r| r.respect += 0.5);
+        world.agents[self.agent].mind.borrow_mut().agitation = 0.0;
+        remember(world, self.agent, format!("the fight with {}", world.agents[self.other].name), -2.0);
+        remember(world, self.other, format!("the fight with {}", agent_name), -2.0);
+        witnessed(world, self.agent, self.other, 0.5);
+        *world.metrics.entry("fight").or_insert(0) += 1;
+    }
+    fn to_string(&self, world: &World) -> String {
+        format!("Picked a fight with {}.", world.agents[self.other].name).to_string()
+    }
+}
+
+// Agents warm to people who see the world the way they do.
+fn shared_outlook(world: &World, a: AgentId, b: AgentId) -> f64 {
+    let a_mind = world.agents[a].mind.borrow();
+    let b_mind = world.agents[b].mind.borrow();
+    let mut agreement: f64 = 0.0;
+    for (id, opinion) in a_mind.opinions_on_others.iter() {
+        if let Some(other) = b_mind.opinions_on_others.get(id) {
+            agreement += if opinion.signum() == other.signum() { 0.1 } else { -0.1 };
+        }
+    }
+    for (id, opinion) in a_mind.opinions_on_places.iter() {
+        if let Some(other) = b_mind.opinions_on_places.get(id) {
+            agreement += if opinion.signum() == other.signum() { 0.02 } else { -0.02 };
+        }
+    }
+    agreement.clamp(-1.0, 1.0)
+}
+
+// The speaker talks about whoever they feel most strongly about, and the
+// listener takes it to heart in proportion to how much they trust them.
+fn gossip(world: &mut World, speaker: AgentId, listener: AgentId) {
+    let (subject, opinion) = {
+        let mind = world.agents[speaker].mind.borrow();
+        let subject = mind.opinions_on_others.iter()
+            .filter(|(id, _)| **id != listener)
+            .max_by(|a, b| a.1.abs().partial_cmp(&b.1.abs()).unwrap());
+        match subject {
+            Some((id, opinion)) if opinion.abs() > 1.0 => (*id, *opinion),
+            _ => return,
+        }
+    };
+    let trust = world.agents[listener].mind.borrow().relationships.get(&speaker).map_or(0.0, |r| r.trust);
+    let weight = (0.5 + 0.25 * trust).clamp(0.0, 1.0);
+    if weight == 0.0 {
+        return;
+    }
+
+    let before = *world.agents[listener].mind.borrow().opinions_on_others.get(&subject).unwrap_or(&0.0);
+    adjust_opinion(world, listener, subject, 0.2 * opinion * weight);
+    let after = *world.agents[listener].mind.borrow().opinions_on_others.get(&subject).unwrap_or(&0.0);
+
+    if (before != 0.0 && before.signum() != after.signum()) || (before == 0.0 && after.abs() > 0.5) {
+        let verdict = if opinion > 0.0 { "a good sort" } else { "not to be trusted" };
+        let message = format!("Heard from {} that {} is {}.",
+                              world.agents[speaker].name, world.agents[subject].name, verdict);
+        record(world, listener, message);
+    }
+    *world.metrics.entry("gossip").or_insert(0) += 1;
+}
+
+// Passing a belief on loses a little certainty each time, and sometimes
+// the details get muddled along the way.
+fn spread_rumor(world: &mut World, speaker: AgentId, listener: AgentId) {
+    let mut rng = rand::thread_rng();
+    let (mut claim, belief) = {
+        let mind = world.agents[speaker].mind.borrow();
+        let rumor = mind.beliefs.iter()
+            .filter(|(_, b)| b.confidence_at(world.time) > 0.3)
+            .choose(&mut rng);
+        match rumor {
+            Some((claim, belief)) => (*claim, *belief),
+            None => return,
+        }
+    };
+
+    if rng.gen::<f64>() < 0.1 + 0.2 * (1.0 - belief.confidence) {
+        claim = match claim {
+            Claim::FoodAt { location, kind } => {
+                match world.locations[location].exits.choose(&mut rng) {
+                    Some(next) if rng.gen() => Claim::FoodAt { location: *next, kind: kind },
+                    _ => {
+                        let foods: Vec<ItemTypeId> = ITEM_TYPES.iter()
+                            .filter(|t| t.is(Category::Food))
+                            .map(|t| t.id)
+                            .collect();
+                        Claim::FoodAt { location: location, kind: *foods.choose(&mut rng).unwrap() }
+                    },
+                }
+            },
+            Claim::Dead { agent } => {
+                let mind = world.agents[speaker].mind.borrow();
+                let known = mind.opinions_on_others.keys().filter(|a| **a != listener).choose(&mut rng);
+                Claim::Dead { agent: *known.unwrap_or(&agent) }
+            },
+        };
+    }
+    if claim == (Claim::Dead { agent: listener }) {
+        return;
+    }
+
+    let trust = world.agents[listener].mind.borrow().relationships.get(&speaker).map_or(0.0, |r| r.trust);
+    // The news keeps its age, so old news stays less convincing.
+    let heard = Belief {
+        source: Source::HeardFrom(speaker),
+        confidence: belief.confidence * 0.8 * (0.5 + 0.25 * trust).clamp(0.1, 1.0),
+        since: belief.since,
+    };
+    {
+        let time = world.time;
+        let mut mind = world.agents[listener].mind.borrow_mut();
+        if mind.beliefs.get(&claim).is_some_and(|b| b.confidence_at(time) >= heard.confidence_at(time)) {
+            return;
+        }
+        mind.beliefs.insert(claim, heard);
+    }
+
+    if let Claim::Dead { agent } = claim {
+        let fond = *world.agents[listener].mind.borrow().opinions_on_others.get(&agent).unwrap_or(&0.0) > 1.0;
+        if fond {
+            let message = format!("Was shocked to hear from {} that {} had died.",
+                                  world.agents[speaker].name, world.agents[agent].name);
+            record(world, listener, message);
+        }
+    }
+    *world.metrics.entry("rumor").or_insert(0) += 1;
+}
+
+// Checks what an agent believes about their surroundings against what is
+// actually there.
+fn observe(world: &mut World, agent: AgentId) {
+    let time = world.time;
+    let location = world.agents[agent].location;
+    let present: HashSet<ItemTypeId> = world.locations[location].items.values()
+        .filter(|i| i.kind().is(Category::Food))
+        .map(|i| i.kind)
+        .collect();
+
+    let mistaken: Vec<(ItemTypeId, Source)> = {
+        let mut mind = world.agents[agent].mind.borrow_mut();
+        let mistaken = mind.beliefs.iter()
+            .filter_map(|(claim, belief)| match claim {
+                Claim::FoodAt { location: l, kind } if *l == location && !present.contains(kind) => Some((*kind, belief.source)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        for (kind, _) in &mistaken {
+            mind.beliefs.remove(&Claim::FoodAt { location: location, kind: *kind });
+        }
+        for kind in &present {
+            mind.beliefs.insert(Claim::FoodAt { location: location, kind: *kind }, Belief::witnessed(time));
+        }
+        // Food gets eaten, so beliefs about it are let go once they've
+        // faded. Deaths are not undone.
+        mind.beliefs.retain(|claim, belief| match claim {
+            Claim::FoodAt { .. } => belief.confidence_at(time) > 0.1,
+            Claim::Dead { .. } => true,
+        });
+        mistaken
+    };
+
+    for (kind, source) in mistaken {
+        if let Source::HeardFrom(teller) = source {
+            let message = format!("Found no {} at the {}, whatever {} had said.",
+                                  ITEM_TYPES[kind].plural, world.locations[location].name, world.agents[teller].name);
+            record(world, agent, message);
+            adjust_relationship(world, agent, teller, |r| r.trust -= 0.5);
+        }
+    }
+
+    let others = world.locations[location].agents.clone();
+    for other in others {
+        if other != agent {
+            found_alive(world, agent, other);
+        }
+    }
+}
+
+fn found_alive(world: &mut World, agent: AgentId, other: AgentId) {
+    if !world.agents[other].health.borrow().alive {
+        return;
+    }
+    let belief = world.agents[agent].mind.borrow_mut().beliefs.remove(&Claim::Dead { agent: other });
+    if let Some(belief) = belief {
+        let message = format!("Was astonished to find {} alive and well.", world.agents[other].name);
+        record(world, agent, message);
+        if let Source::HeardFrom(teller) = belief.source {
+            adjust_relationship(world, agent, teller, |r| r.trust -= 1.0);
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ChatEvent {
+    pub agent: AgentId,
+    pub other: AgentId,
+}
+impl Event for ChatEvent {
+    fn apply(&self, world: &mut World) {
+        if !face_to_face(world, self.agent, self.other) {
+            return;
+        }
+        let cheer = (world.agents[self.agent].mind.borrow().cheer + world.agents[self.other].mind.borrow().cheer) / 2.0;
+        let shift = 0.2 * cheer.clamp(-1.0, 1.0) + shared_outlook(world, self.agent, self.other);
+        adjust_opinion(world, self.agent, self.other, shift);
+        adjust_opinion(world, self.other, self.agent, shift);
+
+        let agent_name = world.agents[self.agent].name.clone();
+        let other_name = world.agents[self.other].name.clone();
+        world.agents[self.agent].events.push(Box::new(*self));
+        world.agents[self.other].events.push(Box::new(DummyEvent {
+            agent: self.other,
+            message: format!("Passed the time with {}.", agent_name).to_string(),
+        }));
+        for a in &[self.agent, self.other] {
+            world.agents[*a].mind.borrow_mut().loneliness = 0.0;
+        }
+        gossip(world, self.agent, self.other);
+        gossip(world, self.other, self.agent);
+        share_food_warnings(world, self.agent, self.other);
+        share_food_warnings(world, self.other, self.agent);
+        spread_rumor(world, self.agent, self.other);
+        spread_rumor(world, self.other, self.agent);
+        if shift > 0.0 {
+            adjust_relationship(world, self.agent, self.other, |r| { r.trust += 0.1; r.attraction += 0.05; });
+            adjust_relationship(world, self.other, self.agent, |r| { r.trust += 0.1; r.attraction += 0.05; });
+            court(world, self.agent, self.other);
+        }
+
+        // Good friends with nobody else to travel with set off together.
+        let time = world.time;
+        let close = |a: AgentId, b: AgentId| {
+            let mind = world.agents[a].mind.borrow();
+            mind.companion.is_none() && *mind.opinions_on_others.get(&b).unwrap_or(&0.0) > 2.0
+        };
+        if close(self.agent, self.other) && close(self.other, self.agent) {
+            let until = time + 24.0 * rand::thread_rng().gen_range(1.0, 4.0);
+            world.agents[self.agent].mind.borrow_mut().companion = Some((self.other, until));
+            world.agents[self.other].mind.borrow_mut().companion = Some((self.agent, until));
+            world.agents[self.agent].events.push(Box::new(DummyEvent {
+                agent: self.agent,
+                message: format!("Decided to travel with {} for a while.", other_name).to_string(),
+            }));
+            world.agents[self.other].events.push(Box::new(DummyEvent {
+                agent: self.other,
+                message: format!("Decided to travel with {} for a while.", agent_name).to_string(),
+            }));
+        }
+        *world.metrics.entry("chat").or_insert(0) += 1;
+    }
+    fn to_string(&self, world: &World) -> String {
+        format!("Spent some time talking with {}.", world.agents[self.other].name).to_string()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct PartEvent {
+    pub agent: AgentId,
+    pub other: AgentId,
+}
+impl Event for PartEvent {
+    fn apply(&self, world: &mut World) {
+        let mut agent_mind = world.agents[self.agent].mind.borrow_mut();
+        if agent_mind.companion.map(|c| c.0) != Some(self.other) {
+            return;
+        }
+        agent_mind.companion = None;
+        drop(agent_mind);
+        world.agents[self.agent].events.push(Box::new(*self));
+
+        let agent_name = world.agents[self.agent].name.clone();
+        let mut other_mind = world.agents[self.other].mind.borrow_mut();
+        if other_mind.companion.map(|c| c.0) == Some(self.agent) {
+            other_mind.companion = None;
+            drop(other_mind);
+            world.agents[self.other].events.push(Box::new(DummyEvent {
+                agent: self.other,
+                message: format!("Parted ways with {}.", agent_name).to_string(),
+            }));
+        }
+    }
+    fn to_string(&self, world: &World) -> String {
+        format!("Parted ways with {}.", world.agents[self.other].name).to_string()
+    }
+}
+
+fn record(world: &mut World, agent: AgentId, message: String) {
+    world.agents[agent].events.push(Box::new(DummyEvent { agent: agent, message: message }));
+}
+
+// Moves a pair of agents along their courtship when they both feel the
+// same way about each other.
+fn court(world: &mut World, a: AgentId, b: AgentId) {
+    let feelings = |world: &World, x: AgentId, y: AgentId| {
+        let mind = world.agents[x].mind.borrow();
+        let affection = *mind.opinions_on_others.get(&y).unwrap_or(&0.0);
+        let relationship = mind.relationships.get(&y).cloned().unwrap_or_else(Relationship::new);
+        (affection, relationship, mind.partner.is_none())
+    };
+    let (a_affection, a_rel, _) = feelings(world, a, b);
+    let (b_affection, b_rel, _) = feelings(world, b, a);
+    let a_name = world.agents[a].name.clone();
+    let b_name = world.agents[b].name.clone();
+    let smitten = |affection: f64, rel: Relationship| affection > 1.0 && rel.attraction > 0.5;
+
+    for (x, y_name, affection, rel) in [(a, &b_name, a_affection, a_rel), (b, &a_name, b_affection, b_rel)] {
+        if rel.courtship == Courtship::Acquainted && smitten(affection, rel) {
+            world.agents[x].mind.borrow_mut().relationships.get_mut(if x == a { &b } else { &a }).unwrap().courtship = Courtship::Interested;
+            record(world, x, format!("Found themself thinking about {} more and more.", y_name));
+        }
+    }
+
+    // Either of them may have just become interested, so look again.
+    let (a_affection, a_rel, a_free) = feelings(world, a, b);
+    let (b_affection, b_rel, b_free) = feelings(world, b, a);
+    let both = |stage: Courtship| a_rel.courtship == stage && b_rel.courtship == stage;
+    let set_stage = |world: &mut World, stage: Courtship| {
+        world.agents[a].mind.borrow_mut().relationships.get_mut(&b).unwrap().courtship = stage;
+        world.agents[b].mind.borrow_mut().relationships.get_mut(&a).unwrap().courtship = stage;
+    };
+
+    if both(Courtship::Interested) && a_affection > 2.0 && b_affection > 2.0 {
+        set_stage(world, Courtship::Courting);
+        record(world, a, format!("Began courting {}.", b_name));
+        record(world, b, format!("Began courting {}.", a_name));
+    } else if both(Courtship::Courting) && a_free && b_free &&
+        a_affection > 4.0 && b_affection > 4.0 && a_rel.trust > 1.0 && b_rel.trust > 1.0 {
+        set_stage(world, Courtship::Partnered);
+        world.agents[a].mind.borrow_mut().partner = Some(b);
+        world.agents[b].mind.borrow_mut().partner = Some(a);
+        let home = world.agents[a].home;
+        world.agents[b].home = home;
+        record(world, a, format!("Became partners with {}, who moved in.", b_name));
+        record(world, b, format!("Became partners with {} and moved in with them.", a_name));
+        remember(world, a, format!("the day {} moved in", b_name), 3.0);
+        remember(world, b, format!("the day they moved in with {}", a_name), 3.0);
+        *world.metrics.entry("partnership").or_insert(0) += 1;
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct BreakupEvent {
+    pub agent: AgentId,
+    pub other: AgentId,
+}
+impl Event for BreakupEvent {
+    fn apply(&self, world: &mut World) {
+        if world.agents[self.agent].mind.borrow().partner != Some(self.other) {
+            return;
+        }
+        world.agents[self.agent].mind.borrow_mut().partner = None;
+        let other_name = world.agents[self.other].name.clone();
+        world.agents[self.other].mind.borrow_mut().partner = None;
+        adjust_relationship(world, self.agent, self.other, |r| r.courtship = Courtship::Separated);
+        adjust_relationship(world, self.other, self.agent, |r| r.courtship = Courtship::Separated);
+        // Whoever ends it is the one who packs up and goes.
+        let agent = &mut world.agents[self.agent];
+        agent.home = agent.storehouse;
+        let agent_name = agent.name.clone();
+        world.agents[self.agent].events.push(Box::new(*self));
+        record(world, self.other, format!("Was left by {}.", agent_name));
+        remember(world, self.agent, format!("leaving {}", other_name), -2.0);
+        remember(world, self.other, format!("being left by {}", agent_name), -3.0);
+        *world.metrics.entry("breakup").or_insert(0) += 1;
+    }
+    fn to_string(&self, world: &World) -> String {
+        format!("Broke up with {} and moved out.", world.agents[self.other].name).to_string()
+    }
+}
+
+// Whether two different agents are both alive and in the same place.
+fn face_to_face(world: &World, a: AgentId, b: AgentId) -> bool {
+    a != b &&
+        world.agents[a].location == world.agents[b].location &&
+        world.agents[a].health.borrow().alive &&
+        world.agents[b].health.borrow().alive
+}
+
+// Stores a memory of something that just happened to the agent, along with
+// where it happened and who was there.
+fn remember(world: &World, agent: AgentId, what: String, feeling: f64) {
+    let location = world.agents[agent].location;
+    let people = world.locations[location].agents.iter()
+        .filter(|a| **a != agent)
+        .cloned()
+        .collect();
+    let description = format!("{} in the {}", what, world.locations[location].name);
+    let mut mind = world.agents[agent].mind.borrow_mut();
+    let id = mind.new_memory_id();
+    mind.memories.push(Memory::new(id, MemoryKind::Event, world.time, location, people, description, feeling));
+}
+
+#[derive(Clone)]
+pub struct RecallEvent {
+    pub agent: AgentId,
+    pub memory: MemoryId,
+    pub description: String,
+}
+impl Event for RecallEvent {
+    fn apply(&self, world: &mut World) {
+        let time = world.time;
+        let (feeling, people) = {
+            let mut mind = world.agents[self.agent].mind.borrow_mut();
+            let (feeling, people) = match mind.memories.iter_mut().find(|m| m.id == self.memory) {
+                Some(memory) => {
+                    // Dwelling on something keeps it fresh.
+                    memory.strength += 0.5;
+                    memory.last_recalled = time;
+                    (memory.feeling, memory.people.clone())
+                },
+                None => return,
+            };
+            mind.cheer += 0.1 * feeling;
+            (feeling, people)
+        };
+        for other in people {
+            if world.agents[self.agent].location == world.agents[other].location {
+                adjust_opinion(world, self.agent, other, 0.1 * feeling);
+            }
+        }
+        world.agents[self.agent].events.push(Box::new(self.clone()));
+        *world.metrics.entry("recall").or_insert(0) += 1;
+    }
+    fn to_string(&self, _: &World) -> String {
+        format!("Remembered {}.", self.description).to_string()
     }
 }
@@ -1,9 +1,16 @@
 use rand::seq::SliceRandom;
+use std::collections::HashSet;
 
-use super::{Agent, Mind, ItemId, World, Event};
-use super::super::DummyEvent;
+use super::{Agent, AgentId, Mind, Item, ItemId, LocationId, World, Event, FAVOR_LIMIT, MAX_CARRY_WEIGHT};
+use super::super::{DummyEvent, WaterSource};
+use super::super::farming::CropStage;
+use super::super::wildlife::CreatureId;
 use super::events;
 use super::daemons;
+use super::personality::Personality;
+use super::beliefs::Claim;
+use super::professions::Profession;
+use super::super::items::{self, Category, Recipe, RECIPES};
 
 pub enum StrategyState {
     Complete { events: Vec<Box<dyn Event>> },
@@ -15,17 +22,27 @@ pub trait Strategy {
 }
 
 pub struct FindSolitude {
+    shelter: bool,
     payload: fn(agent: &Agent, world: &World) -> Vec<Box<dyn Event>>,
 }
 
 impl Strategy for FindSolitude {
     fn step_simulation(&mut self, agent: &Agent, world: &World) -> StrategyState {
         let location = &world.locations[agent.location];
-        if location.agents.len() > 1 {
+        // Extroverts don't mind a little company while they nap or poop.
+        let tolerated_company = ((1.0 - agent.personality.introversion) * 3.0) as usize;
+        let exposed = location.shelter.is_none() &&
+            world.temperature_at(location.id) + agent.clothing_warmth() < daemons::COMFORTABLE_TEMPERATURE;
+        if location.agents.len() > 1 + tolerated_company {
             StrategyState::Incomplete { events: vec![
                 Box::new(DummyEvent { agent: agent.id, message: "I'm not alone...".to_string() }),
                 wander(agent, world),
             ]}
+        } else if self.shelter && exposed {
+            StrategyState::Incomplete { events: vec![
+                Box::new(DummyEvent { agent: agent.id, message: "Too cold to sleep out here...".to_string() }),
+                wander(agent, world),
+            ]}
         } else {
             StrategyState::Complete { events: (self.payload)(agent, world) }
         }
@@ -38,19 +55,69 @@ pub fn wander(agent: &Agent, world: &World) -> Box<dyn Event> {
     Box::new(events::MoveEvent { start: agent.location, end: new_loc, agent: agent.id })
 }
 
+// Follows a route to the target, working it out again whenever the agent
+// has strayed from it.
+pub fn travel(agent: &Agent, world: &World, route: &mut Vec<LocationId>, target: LocationId) -> Box<dyn Event> {
+    let stale = match (route.first(), route.last()) {
+        (Some(end), Some(next)) => *end != target || !world.locations[agent.location].exits.contains(next),
+        _ => true,
+    };
+    if stale {
+        *route = world.path(agent.location, target).unwrap_or_default();
+        route.reverse();
+    }
+    match route.pop() {
+        Some(next) => Box::new(events::MoveEvent { start: agent.location, end: next, agent: agent.id }),
+        None => wander(agent, world),
+    }
+}
+
+// Heads for the nearest place that suits, keeping to the route already
+// worked out while it still leads somewhere suitable.
+pub fn head_for<F: Fn(LocationId) -> bool>(agent: &Agent, world: &World, route: &mut Vec<LocationId>, suits: F) -> Option<Box<dyn Event>> {
+    let on_course = match (route.first(), route.last()) {
+        (Some(end), Some(next)) => suits(*end) && world.locations[agent.location].exits.contains(next),
+        _ => false,
+    };
+    if !on_course {
+        *route = world.path_to_nearest(agent.location, suits)?;
+        route.reverse();
+    }
+    let next = route.pop()?;
+    Some(Box::new(events::MoveEvent { start: agent.location, end: next, agent: agent.id }))
+}
+
+// Something worth hunting at the agent's location.
+pub fn prey(agent: &Agent, world: &World) -> Option<CreatureId> {
+    world.locations[agent.location].creatures.iter()
+        .filter(|c| !world.creatures[**c].species.predator())
+        .copied()
+        .next()
+}
+
 pub struct FindFood {
+    route: Vec<LocationId>,
     payload: fn(item: &ItemId, agent: &Agent, world: &World) -> Vec<Box<dyn Event>>,
 }
 
 impl Strategy for FindFood {
     fn step_simulation(&mut self, agent: &Agent, world: &World) -> StrategyState {
-        match agent.inventory.iter().find(|i| i.1.food_value > 0.0) {
+        let mind = agent.mind.borrow();
+        let trusted = |item: &Item| {
+            item.kind().is(Category::Food) && *mind.opinions_on_foods.get(&item.kind).unwrap_or(&0.0) >= 0.0
+        };
+        // Rotten food only goes down when the alternative is starving.
+        let desperate = agent.health.borrow().hunger > 24.0;
+        let carried = agent.inventory.iter()
+            .filter(|i| trusted(i.1))
+            .find(|i| desperate || !i.1.spoiled(world.time));
+        match carried {
             Some((id, _)) => {
                 StrategyState::Complete { events: (self.payload)(id, agent, world) }
             },
             None => {
                 let location = &world.locations[agent.location];
-                match location.items.iter().find(|i| i.1.food_value > 0.0) {
+                match location.items.iter().find(|i| trusted(i.1) && agent.can_carry(i.1)) {
                     Some((id, _)) => {
                         StrategyState::Incomplete { events: vec![
                             Box::new(events::PickupEvent{
@@ -60,10 +127,40 @@ impl Strategy for FindFood {
                             }),
                         ]}
                     },
+                    None if agent.has(items::KNIFE) && prey(agent, world).is_some() => {
+                        StrategyState::Incomplete { events: vec![
+                            Box::new(events::HuntEvent { agent: agent.id, creature: prey(agent, world).unwrap() }),
+                        ]}
+                    },
+                    None if location.water == Some(WaterSource::Stream) && agent.has(items::FISHING_ROD) => {
+                        StrategyState::Incomplete { events: vec![
+                            Box::new(events::FishEvent { agent: agent.id, location: location.id }),
+                        ]}
+                    },
                     None => {
+                        // Villagers in good standing can draw on the storehouse.
+                        let storehouse = &world.locations[agent.storehouse];
+                        let in_credit = *storehouse.favors.get(&agent.id).unwrap_or(&0.0) > FAVOR_LIMIT;
+                        let stocked = storehouse.items.values().any(trusted);
+                        // Otherwise go to the nearest place they still
+                        // believe has food.
+                        let rumored: HashSet<LocationId> = mind.beliefs.iter()
+                            .filter_map(|(claim, belief)| match claim {
+                                Claim::FoodAt { location, kind } if *location != agent.location &&
+                                    *mind.opinions_on_foods.get(kind).unwrap_or(&0.0) >= 0.0 &&
+                                    belief.confidence_at(world.time) > 0.2 => Some(*location),
+                                _ => None,
+                            })
+                            .collect();
+                        let next_step = if in_credit && stocked && agent.profession.is_some() {
+                            travel(agent, world, &mut self.route, agent.storehouse)
+                        } else {
+                            head_for(agent, world, &mut self.route, |l| rumored.contains(&l))
+                                .unwrap_or_else(|| wander(agent, world))
+                        };
                         StrategyState::Incomplete { events: vec![
                             Box::new(DummyEvent { agent: agent.id, message: "Nothing to eat here...".to_string() }),
-                            wander(agent, world),
+                            next_step,
                         ]}
                     },
                 }
@@ -72,6 +169,385 @@ impl Strategy for FindFood {
     }
 }
 
+pub struct Gather {
+    route: Vec<LocationId>,
+}
+
+impl Strategy for Gather {
+    fn step_simulation(&mut self, agent: &Agent, world: &World) -> StrategyState {
+        let mind = agent.mind.borrow();
+        let wanted = 24.0 * 2.0 * Personality::scale(agent.personality.appetite);
+        let location = &world.locations[agent.location];
+
+        if let Some(rotten) = agent.inventory().find(|i| i.spoiled(world.time)) {
+            return StrategyState::Incomplete { events: vec![
+                Box::new(events::DropEvent {
+                    location: location.id,
+                    agent: agent.id,
+                    item: rotten.id,
+                }),
+            ]};
+        }
+
+        let full = agent.carried_weight() > MAX_CARRY_WEIGHT * 0.9 ||
+            agent.carried_volume() > agent.max_carry_volume() * 0.9;
+        if !full && agent.carried_food_value(world.time) < wanted {
+            let forage = location.items.values().find(|i| {
+                i.kind().is(Category::Food) && agent.can_carry(i) &&
+                    *mind.opinions_on_foods.get(&i.kind).unwrap_or(&0.0) >= 0.0
+            });
+            match forage {
+                Some(item) if agent.location != agent.home => {
+                    return StrategyState::Incomplete { events: vec![
+                        Box::new(events::PickupEvent{
+                            location: location.id,
+                            agent: agent.id,
+                            item: item.id,
+                        }),
+                    ]};
+                },
+                _ => {
+                    return StrategyState::Incomplete { events: vec![wander(agent, world)] };
+                },
+            }
+        }
+
+        if agent.location == agent.home {
+            StrategyState::Complete { events: vec![
+                Box::new(events::StockpileEvent { agent: agent.id, location: agent.location }),
+            ]}
+        } else {
+            StrategyState::Incomplete { events: vec![travel(agent, world, &mut self.route, agent.home)] }
+        }
+    }
+}
+
+pub struct Craft {
+    recipe: Option<&'static Recipe>,
+    hours_worked: u32,
+}
+
+impl Strategy for Craft {
+    fn step_simulation(&mut self, agent: &Agent, world: &World) -> StrategyState {
+        if self.recipe.is_none() {
+            self.recipe = RECIPES.iter().find(|r| {
+                !agent.has(r.output) && r.tools.iter().all(|t| agent.has(*t))
+            });
+        }
+        let recipe = match self.recipe {
+            Some(recipe) => recipe,
+            None => return StrategyState::Complete { events: vec![] },
+        };
+
+        let missing: Vec<items::ItemTypeId> = recipe.inputs.iter()
+            .filter(|(kind, count)| agent.count_carried(*kind) < *count)
+            .map(|(kind, _)| *kind)
+            .collect();
+
+        if missing.is_empty() {
+            self.hours_worked += 1;
+            if self.hours_worked >= recipe.hours {
+                StrategyState::Complete { events: vec![
+                    Box::new(events::CraftEvent { agent: agent.id, output: recipe.output }),
+                ]}
+            } else {
+                StrategyState::Incomplete { events: vec![] }
+            }
+        } else {
+            let location = &world.locations[agent.location];
+            match location.items.values().find(|i| missing.contains(&i.kind) && agent.can_carry(i)) {
+                Some(item) => StrategyState::Incomplete { events: vec![
+                    Box::new(events::PickupEvent{
+                        location: location.id,
+                        agent: agent.id,
+                        item: item.id,
+                    }),
+                ]},
+                None => StrategyState::Incomplete { events: vec![wander(agent, world)] },
+            }
+        }
+    }
+}
+
+enum WorkStage {
+    Commute,
+    Working(u32),
+    Deliver,
+}
+
+pub struct Work {
+    stage: WorkStage,
+    workplace: Option<LocationId>,
+    route: Vec<LocationId>,
+}
+
+impl Work {
+    fn at_work(&self, profession: Profession, agent: &Agent, world: &World) -> bool {
+        if profession.works_in_forest() {
+            world.locations[agent.location].name == "forest"
+        } else {
+            self.workplace == Some(agent.location)
+        }
+    }
+}
+
+impl Strategy for Work {
+    fn step_simulation(&mut self, agent: &Agent, world: &World) -> StrategyState {
+        let profession = match agent.profession {
+            Some(profession) => profession,
+            None => return StrategyState::Complete { events: vec![] },
+        };
+        if self.workplace.is_none() {
+            self.workplace = match profession {
+                Profession::Farmer => Some(agent.home),
+                Profession::Baker => Some(agent.storehouse),
+                Profession::Trader => {
+                    // With only one village there's nobody to trade with.
+                    let mut rng = rand::thread_rng();
+                    let others: Vec<LocationId> = world.storehouses().into_iter().filter(|s| *s != agent.storehouse).collect();
+                    match others.choose(&mut rng) {
+                        Some(other) => Some(*other),
+                        None => return StrategyState::Complete { events: vec![] },
+                    }
+                },
+                Profession::Hunter | Profession::Woodcutter => None,
+            };
+        }
+
+        match self.stage {
+            WorkStage::Commute => {
+                if self.at_work(profession, agent, world) {
+                    self.stage = WorkStage::Working(0);
+                    StrategyState::Incomplete { events: vec![] }
+                } else {
+                    match self.workplace {
+                        Some(workplace) => StrategyState::Incomplete { events: vec![travel(agent, world, &mut self.route, workplace)] },
+                        None => StrategyState::Incomplete { events: vec![wander(agent, world)] },
+                    }
+                }
+            },
+            WorkStage::Working(hours) => {
+                if hours + 1 >= profession.hours() {
+                    self.stage = WorkStage::Deliver;
+                    StrategyState::Incomplete { events: vec![
+                        Box::new(events::WorkEvent { agent: agent.id, location: agent.location, profession: profession }),
+                    ]}
+                } else if profession == Profession::Hunter {
+                    self.stage = WorkStage::Working(hours + 1);
+                    match prey(agent, world) {
+                        Some(creature) => StrategyState::Incomplete { events: vec![
+                            Box::new(events::HuntEvent { agent: agent.id, creature: creature }),
+                        ]},
+                        None => StrategyState::Incomplete { events: vec![wander(agent, world)] },
+                    }
+                } else {
+                    self.stage = WorkStage::Working(hours + 1);
+                    StrategyState::Incomplete { events: vec![] }
+                }
+            },
+            WorkStage::Deliver => {
+                if agent.location == agent.storehouse {
+                    StrategyState::Complete { events: vec![
+                        Box::new(events::DepositEvent { agent: agent.id, location: agent.location }),
+                    ]}
+                } else {
+                    StrategyState::Incomplete { events: vec![travel(agent, world, &mut self.route, agent.storehouse)] }
+                }
+            },
+        }
+    }
+}
+
+pub fn field_needing_work(agent: &Agent, world: &World) -> Option<LocationId> {
+    let season = world.season();
+    world.locations.iter()
+        .filter(|l| l.village == Some(agent.storehouse))
+        .filter(|l| l.field.as_ref().is_some_and(|f| f.needs_work(season)))
+        .map(|l| l.id)
+        .next()
+}
+
+pub struct Farm {
+    stage: WorkStage,
+    field: Option<LocationId>,
+    route: Vec<LocationId>,
+}
+
+impl Strategy for Farm {
+    fn step_simulation(&mut self, agent: &Agent, world: &World) -> StrategyState {
+        if self.field.is_none() {
+            self.field = field_needing_work(agent, world);
+        }
+        let field = match self.field {
+            Some(field) => field,
+            None => return StrategyState::Complete { events: vec![] },
+        };
+
+        match self.stage {
+            WorkStage::Commute => {
+                if agent.location == field {
+                    self.stage = WorkStage::Working(0);
+                    StrategyState::Incomplete { events: vec![] }
+                } else {
+                    StrategyState::Incomplete { events: vec![travel(agent, world, &mut self.route, field)] }
+                }
+            },
+            WorkStage::Working(hours) => {
+                if hours < 2 {
+                    self.stage = WorkStage::Working(hours + 1);
+                    return StrategyState::Incomplete { events: vec![] };
+                }
+                let event = Box::new(events::FarmEvent { agent: agent.id, location: field });
+                let ripe = world.locations[field].field.as_ref().is_some_and(|f| f.stage == CropStage::Ripe);
+                if ripe {
+                    self.stage = WorkStage::Deliver;
+                    StrategyState::Incomplete { events: vec![event] }
+                } else {
+                    StrategyState::Complete { events: vec![event] }
+                }
+            },
+            WorkStage::Deliver => {
+                if agent.location == agent.storehouse {
+                    StrategyState::Complete { events: vec![
+                        Box::new(events::DepositEvent { agent: agent.id, location: agent.location }),
+                    ]}
+                } else {
+                    StrategyState::Incomplete { events: vec![travel(agent, world, &mut self.route, agent.storehouse)] }
+                }
+            },
+        }
+    }
+}
+
+pub struct Socialize {
+    target: Option<AgentId>,
+    route: Vec<LocationId>,
+}
+
+impl Strategy for Socialize {
+    fn step_simulation(&mut self, agent: &Agent, world: &World) -> StrategyState {
+        let company: Vec<AgentId> = world.locations[agent.location].agents.iter()
+            .filter(|a| {
+                let health = world.agents[**a].health.borrow();
+                **a != agent.id && health.alive && health.awake
+            })
+            .copied()
+            .collect();
+
+        let mind = agent.mind.borrow();
+        if self.target.is_none() {
+            // Seek out the best liked friend, or failing that anyone at all.
+            self.target = mind.opinions_on_others.iter()
+                .filter(|(a, o)| **o > 0.0 && world.agents[**a].health.borrow().alive)
+                .max_by(|x, y| x.1.partial_cmp(y.1).unwrap())
+                .map(|(a, _)| *a);
+        }
+
+        let partner = match self.target {
+            Some(target) if company.contains(&target) => Some(target),
+            Some(_) => None,
+            None => company.iter()
+                .filter(|a| *mind.opinions_on_others.get(a).unwrap_or(&0.0) >= 0.0)
+                .copied()
+                .next(),
+        };
+
+        match (partner, self.target) {
+            (Some(other), _) => StrategyState::Complete { events: vec![
+                Box::new(events::ChatEvent { agent: agent.id, other: other }),
+            ]},
+            (None, Some(target)) => {
+                let destination = world.agents[target].location;
+                if self.route.first() != Some(&destination) {
+                    self.route.clear();
+                }
+                StrategyState::Incomplete { events: vec![travel(agent, world, &mut self.route, destination)] }
+            },
+            (None, None) => StrategyState::Incomplete { events: vec![
+                Box::new(DummyEvent { agent: agent.id, message: "Looked for some company...".to_string() }),
+                wander(agent, world),
+            ]},
+        }
+    }
+}
+
+// Partners go home to sleep together rather than looking for a quiet spot.
+pub struct SleepAtHome {
+    route: Vec<LocationId>,
+}
+
+impl Strategy for SleepAtHome {
+    fn step_simulation(&mut self, agent: &Agent, world: &World) -> StrategyState {
+        if agent.location == agent.home {
+            StrategyState::Complete { events: vec![
+                Box::new(events::NapEvent { agent: agent.id }),
+            ]}
+        } else {
+            StrategyState::Incomplete { events: vec![travel(agent, world, &mut self.route, agent.home)] }
+        }
+    }
+}
+
+pub struct FindWater {
+    route: Vec<LocationId>,
+    payload: fn(agent: &Agent, world: &World) -> Vec<Box<dyn Event>>,
+}
+
+impl Strategy for FindWater {
+    fn step_simulation(&mut self, agent: &Agent, world: &World) -> StrategyState {
+        if world.locations[agent.location].water.is_some() {
+            return StrategyState::Complete { events: (self.payload)(agent, world) };
+        }
+        // Make for the nearest water they've seen, or the well at home.
+        let mind = agent.mind.borrow();
+        let known = |l: LocationId| {
+            world.locations[l].water.is_some() && (l == agent.home || mind.opinions_on_places.contains_key(&l))
+        };
+        let next_step = head_for(agent, world, &mut self.route, known).unwrap_or_else(|| wander(agent, world));
+        StrategyState::Incomplete { events: vec![
+            Box::new(DummyEvent { agent: agent.id, message: "No water here...".to_string() }),
+            next_step,
+        ]}
+    }
+}
+
+pub struct FindShelter {
+    route: Vec<LocationId>,
+    payload: fn(agent: &Agent, world: &World) -> Vec<Box<dyn Event>>,
+}
+
+impl Strategy for FindShelter {
+    fn step_simulation(&mut self, agent: &Agent, world: &World) -> StrategyState {
+        let location = &world.locations[agent.location];
+        if location.shelter.is_some() {
+            return StrategyState::Complete { events: (self.payload)(agent, world) };
+        }
+
+        if agent.clothing_warmth() <= 0.0 {
+            if let Some((id, _)) = location.items.iter().find(|i| i.1.kind().is(Category::Clothing)) {
+                return StrategyState::Incomplete { events: vec![
+                    Box::new(events::PickupEvent{
+                        location: location.id,
+                        agent: agent.id,
+                        item: *id,
+                    }),
+                ]};
+            }
+        }
+
+        // Make for home or the nearest cave they remember.
+        let mind = agent.mind.borrow();
+        let known = |l: LocationId| {
+            world.locations[l].shelter.is_some() && (l == agent.home || mind.opinions_on_places.contains_key(&l))
+        };
+        let next_step = head_for(agent, world, &mut self.route, known).unwrap_or_else(|| wander(agent, world));
+        StrategyState::Incomplete { events: vec![
+            Box::new(DummyEvent { agent: agent.id, message: "It's cold out here...".to_string() }),
+            next_step,
+        ]}
+    }
+}
+
 pub struct Explore {
     iterations: u32,
     payload: fn(agent: &Agent, world: &World) -> Vec<Box<dyn Event>>,
@@ -92,6 +568,13 @@ impl Strategy for Explore {
 #[derive(Hash, PartialEq, Copy, Clone)]
 pub enum Goal {
     FindFood,
+    Gather,
+    Craft,
+    Work,
+    Farm,
+    Socialize,
+    Drink,
+    KeepWarm,
     Rest,
     Shit,
     Explore,
@@ -99,7 +582,25 @@ pub enum Goal {
 
 impl Eq for Goal {}
 
-fn choose_goal(mind: &mut Mind) -> bool {
+impl Goal {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Goal::FindFood => "find food",
+            Goal::Gather => "gather",
+            Goal::Craft => "craft",
+            Goal::Work => "work",
+            Goal::Farm => "farm",
+            Goal::Socialize => "socialize",
+            Goal::Drink => "drink",
+            Goal::KeepWarm => "keep warm",
+            Goal::Rest => "rest",
+            Goal::Shit => "shit",
+            Goal::Explore => "explore",
+        }
+    }
+}
+
+fn choose_goal(mind: &mut Mind, personality: &Personality) -> bool {
     let mut rng = rand::thread_rng();
     let goals: Vec<(&Goal, &f64)> = mind.goals.iter().collect();
     match goals.choose_weighted(&mut rng, |k| k.1) {
@@ -112,6 +613,7 @@ fn choose_goal(mind: &mut Mind) -> bool {
                 None => {
                     match k {
                         Goal::FindFood => { mind.current_goal = Some((**k, Box::new(FindFood {
+                            route: Vec::new(),
                             payload: |item, agent, _| {
                                 vec![
                                     Box::new(events::EatEvent {
@@ -121,16 +623,64 @@ fn choose_goal(mind: &mut Mind) -> bool {
                                 ]
                             }
                         }))); },
+                        Goal::Gather => {
+                            mind.current_goal = Some((**k, Box::new(Gather { route: Vec::new() })));
+                        },
+                        Goal::Craft => {
+                            mind.current_goal = Some((**k, Box::new(Craft { recipe: None, hours_worked: 0 })));
+                        },
+                        Goal::Work => {
+                            mind.current_goal = Some((**k, Box::new(Work {
+                                stage: WorkStage::Commute,
+                                workplace: None,
+                                route: Vec::new(),
+                            })));
+                        },
+                        Goal::Farm => {
+                            mind.current_goal = Some((**k, Box::new(Farm {
+                                stage: WorkStage::Commute,
+                                field: None,
+                                route: Vec::new(),
+                            })));
+                        },
+                        Goal::Socialize => {
+                            mind.current_goal = Some((**k, Box::new(Socialize { target: None, route: Vec::new() })));
+                        },
+                        Goal::Drink => {
+                            mind.current_goal = Some((**k, Box::new(FindWater { route: Vec::new(), payload:
+                                |agent, _| {
+                                    vec![
+                                        Box::new(events::DrinkEvent {
+                                            agent: agent.id,
+                                            location: agent.location,
+                                        }),
+                                    ]}
+                                })));
+                        },
+                        Goal::KeepWarm => {
+                            mind.current_goal = Some((**k, Box::new(FindShelter { route: Vec::new(), payload:
+                                |agent, _| {
+                                    vec![
+                                        Box::new(events::ShelterEvent {
+                                            agent: agent.id,
+                                            location: agent.location,
+                                        }),
+                                    ]}
+                                })));
+                        },
                         Goal::Shit => {
-                            mind.current_goal = Some((**k, Box::new(FindSolitude { payload: 
+                            mind.current_goal = Some((**k, Box::new(FindSolitude { shelter: false, payload: 
                                 |agent, _| {
                                     vec![
                                         Box::new(events::DefecateEvent { agent: agent.id }),
                                     ]}
                                 })));
                         },
+                        Goal::Rest if mind.partner.is_some() => {
+                            mind.current_goal = Some((**k, Box::new(SleepAtHome { route: Vec::new() })));
+                        },
                         Goal::Rest => {
-                            mind.current_goal = Some((**k, Box::new(FindSolitude { payload:
+                            mind.current_goal = Some((**k, Box::new(FindSolitude { shelter: true, payload:
                                 |agent, _| {
                                     vec![
                                         Box::new(events::NapEvent { agent: agent.id }),
@@ -138,9 +688,10 @@ fn choose_goal(mind: &mut Mind) -> bool {
                                 })));
                         }
                         Goal::Explore => {
+                            let iterations = (5.0 * Personality::scale(personality.curiosity)).round() as u32;
                             mind.current_goal = Some((**k, Box::new(Explore { 
-                                iterations: 5,
-                                payload: |agent, _| {
+                                iterations: iterations.max(1),
+                                payload: |_, _| {
                                     vec![
                                     ]}
                                 })));
@@ -165,13 +716,13 @@ impl daemons::Daemon for Executive {
         let mut mind = agent.mind.borrow_mut();
 
         if mind.current_goal.is_none() {
-            if mind.paused_goals.len() > 0 {
-                let idxs:Vec<usize> = (0..mind.paused_goals.len()).into_iter().collect();
+            if !mind.paused_goals.is_empty() {
+                let idxs:Vec<usize> = (0..mind.paused_goals.len()).collect();
                 let restart_goal = idxs.choose_weighted(&mut rng, |k| mind.goals[&mind.paused_goals[*k].0]).unwrap();
                 mind.current_goal = Some(mind.paused_goals.remove(*restart_goal));
                 Some(1.0)
             } else {
-                if choose_goal(&mut mind) {
+                if choose_goal(&mut mind, &agent.personality) {
                     Some(1.0)
                 } else {
                     None
@@ -180,22 +731,24 @@ impl daemons::Daemon for Executive {
         } else {
             let max_goal = mind.goals.values().cloned().fold(-1./0. , f64::max);
             if max_goal > mind.goals.get(&mind.current_goal.as_ref().unwrap().0).unwrap_or(&0.0) * 1.20 {
-                choose_goal(&mut mind);
+                choose_goal(&mut mind, &agent.personality);
             }
             Some(1.0)
         }
     }
 
     fn events(&self, agent: &Agent, world: &World) -> Vec<Box<dyn Event>> {
-        let mut mind = agent.mind.borrow_mut();
-        match &mut mind.current_goal {
-            Some((_, strategy)) => {
+        // Take the goal out of the mind while it runs so strategies are free
+        // to consult what the agent knows.
+        let current_goal = agent.mind.borrow_mut().current_goal.take();
+        match current_goal {
+            Some((goal, mut strategy)) => {
                 match strategy.step_simulation(agent, world) {
-                    StrategyState::Complete {  events } => {
-                        mind.current_goal = None;
+                    StrategyState::Complete {  events } => events,
+                    StrategyState::Incomplete { events } => {
+                        agent.mind.borrow_mut().current_goal = Some((goal, strategy));
                         events
-                    },
-                    StrategyState::Incomplete { events } => events
+                    }
                 }
             },
             None => vec![]
@@ -0,0 +1,44 @@
+use super::AgentId;
+use super::super::LocationId;
+
+pub type MemoryId = usize;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum MemoryKind {
+    // Caught out in bad weather.
+    Storm,
+    // Anything else that happened to them.
+    Event,
+}
+
+// Something that happened to an agent which stuck with them. Memories fade
+// unless they are strong or keep coming back.
+pub struct Memory {
+    pub id: MemoryId,
+    pub kind: MemoryKind,
+    pub time: f64,
+    pub location: LocationId,
+    pub people: Vec<AgentId>,
+    pub description: String,
+    // How the agent felt about it, from bad to good.
+    pub feeling: f64,
+    pub strength: f64,
+    pub last_recalled: f64,
+}
+
+impl Memory {
+    pub fn new(id: MemoryId, kind: MemoryKind, time: f64, location: LocationId, people: Vec<AgentId>,
+               description: String, feeling: f64) -> Memory {
+        Memory {
+            id: id,
+            kind: kind,
+            time: time,
+            location: location,
+            people: people,
+            description: description,
+            feeling: feeling,
+            strength: feeling.abs(),
+            last_recalled: time,
+        }
+    }
+}
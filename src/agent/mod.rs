@@ -1,25 +1,48 @@
+mod ailments;
 mod events;
 mod names;
 mod executive;
 mod daemons;
+mod personality;
+mod professions;
+mod relationships;
+mod beliefs;
+mod memories;
 
 use rand::seq::SliceRandom;
-use rand::seq::IteratorRandom;
 use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
 
 use super::{Event, Item, ItemId, World, LocationId};
+use super::items::{Category, ItemTypeId};
+use super::wildlife::CreatureId;
+use self::personality::Personality;
+pub use self::professions::Profession;
+pub(crate) use self::events::AttackEvent;
+
+pub const MAX_CARRY_WEIGHT: f64 = 10.0;
+pub const MAX_CARRY_VOLUME: f64 = 12.0;
+// How far into debt a storehouse will let someone go.
+pub const FAVOR_LIMIT: f64 = -10.0;
+// How many steps of an agent's trail are kept.
+pub const MAX_TRAIL: usize = 2000;
 
 pub type AgentId = usize;
 pub struct Agent {
     pub id: AgentId,
     pub name: String,
     pub location: usize,
-    pub events: Vec<Box<dyn Event>>,
+    pub home: LocationId,
+    pub storehouse: LocationId,
+    pub profession: Option<Profession>,
+    pub(crate) events: Vec<Box<dyn Event>>,
+    // The locations the agent has most recently passed through, in order.
+    pub trail: Vec<LocationId>,
     inventory: HashMap<ItemId, Item>,
 
     pub health: RefCell<Health>,
     mind: RefCell<Mind>,
+    pub personality: Personality,
 
     pub total_time: Cell<f64>,
 
@@ -30,28 +53,169 @@ impl Agent {
     pub fn new(id: AgentId) -> Agent {
         Agent {
             id: id,
-            name: names::male_name(),
+            name: names::random_name(),
             total_time: Cell::new(0.0),
             location: 0,
+            home: 0,
+            storehouse: 0,
+            profession: None,
             events: Vec::with_capacity(1000),
+            trail: Vec::with_capacity(MAX_TRAIL),
             inventory: HashMap::with_capacity(10),
 
             health: RefCell::new(Health::new()),
             mind: RefCell::new(Mind::new()),
-            
+            personality: Personality::new(),
+
             daemons: vec![
                 Box::new(daemons::Wanderlust { last_wander: Cell::new(0.0) }),
                 Box::new(daemons::HungerTracker {}),
+                Box::new(daemons::Provider {}),
+                Box::new(daemons::Crafter {}),
+                Box::new(daemons::Workday {}),
+                Box::new(daemons::Farming {}),
+                Box::new(daemons::ThirstTracker {}),
+                Box::new(daemons::WarmthTracker {}),
                 Box::new(daemons::SleepTracker {}),
                 Box::new(daemons::PoopTracker {}),
                 Box::new(daemons::PainTracker {}),
+                Box::new(daemons::AilmentTracker::new()),
+                Box::new(daemons::Caretaker::new()),
+                Box::new(daemons::Trader::new()),
+                Box::new(daemons::Antagonism::new()),
                 Box::new(daemons::EncounterTracker::new()),
+                Box::new(daemons::Loneliness {}),
+                Box::new(daemons::Companionship::new()),
+                Box::new(daemons::Romance::new()),
+                Box::new(daemons::Reminiscence::new()),
                 Box::new(executive::Executive {}),
             ],
         }
     }
 
-    pub fn step_simulation(&self, world: &World) -> Vec<Box<dyn Event>> {
+    pub fn inventory(&self) -> impl Iterator<Item=&Item> {
+        self.inventory.values()
+    }
+
+    pub fn carried_weight(&self) -> f64 {
+        self.inventory.values().map(|i| i.kind().weight).sum()
+    }
+
+    pub fn carried_volume(&self) -> f64 {
+        self.inventory.values().map(|i| i.kind().volume).sum()
+    }
+
+    // Baskets and the like let an agent carry more than their arms can hold.
+    pub fn max_carry_volume(&self) -> f64 {
+        MAX_CARRY_VOLUME + self.inventory.values().map(|i| i.kind().capacity).sum::<f64>()
+    }
+
+    pub fn can_carry(&self, item: &Item) -> bool {
+        self.carried_weight() + item.kind().weight <= MAX_CARRY_WEIGHT &&
+            self.carried_volume() + item.kind().volume <= self.max_carry_volume()
+    }
+
+    pub fn count_carried(&self, kind: ItemTypeId) -> usize {
+        self.inventory.values().filter(|i| i.kind == kind).count()
+    }
+
+    pub fn has(&self, kind: ItemTypeId) -> bool {
+        self.inventory.values().any(|i| i.kind == kind)
+    }
+
+    pub fn carried_food_value(&self, time: f64) -> f64 {
+        self.inventory.values()
+            .filter(|i| i.kind().is(Category::Food) && !i.spoiled(time))
+            .map(|i| i.kind().food_value)
+            .sum()
+    }
+
+    pub fn opinions_on_others(&self) -> HashMap<AgentId, f64> {
+        self.mind.borrow().opinions_on_others.clone()
+    }
+
+    pub fn partner(&self) -> Option<AgentId> {
+        self.mind.borrow().partner
+    }
+
+    pub fn current_goal(&self) -> Option<&'static str> {
+        self.mind.borrow().current_goal.as_ref().map(|g| g.0.name())
+    }
+
+    // Notes a step along the agent's trail.
+    pub(crate) fn walk(&mut self, start: LocationId, end: LocationId) {
+        if self.trail.is_empty() {
+            self.trail.push(start);
+        }
+        // Forget the older half at once rather than shifting every step.
+        if self.trail.len() >= MAX_TRAIL {
+            self.trail.drain(..MAX_TRAIL / 2);
+        }
+        self.trail.push(end);
+    }
+
+    // Drops whatever the agent was in the middle of, routes and all. Their
+    // goals remain, so they will pick them up again from wherever they are.
+    pub(crate) fn forget_plans(&self) {
+        let mut mind = self.mind.borrow_mut();
+        mind.current_goal = None;
+        mind.paused_goals.clear();
+    }
+
+    // Everything about the agent's body and mind, for debugging.
+    pub fn describe(&self, world: &World) -> String {
+        let health = self.health.borrow();
+        let mind = self.mind.borrow();
+        let mut lines = Vec::new();
+
+        let profession = self.profession.map_or("none", |p| p.name());
+        lines.push(format!("{} ({}), {}, at {} (home {}, storehouse {})",
+                           self.name, self.id, profession, self.location, self.home, self.storehouse));
+        lines.push(format!("alive: {}  awake: {}  hunger: {:.1}  thirst: {:.1}  temperature: {:.1}",
+                           health.alive, health.awake, health.hunger, health.thirst, health.temperature));
+        lines.push(format!("sleepiness: {:.1}  poop: {:.1}  pain: {:.1}  ailments: {}",
+                           health.sleepiness, health.poop, health.pain,
+                           health.ailments.iter().map(|a| a.kind.name()).collect::<Vec<_>>().join(", ")));
+        lines.push(format!("current goal: {}", mind.current_goal.as_ref().map_or("none", |g| g.0.name())));
+        lines.push(format!("goals: {}", mind.goals.iter()
+                           .map(|(g, u)| format!("{} {:.2}", g.name(), u))
+                           .collect::<Vec<_>>().join(", ")));
+        lines.push(format!("paused goals: {}", mind.paused_goals.iter()
+                           .map(|g| g.0.name())
+                           .collect::<Vec<_>>().join(", ")));
+        lines.push(format!("agitation: {:.2}  cheer: {:.2}  loneliness: {:.2}", mind.agitation, mind.cheer, mind.loneliness));
+        if let Some(partner) = mind.partner {
+            lines.push(format!("partner: {}", world.agents[partner].name));
+        }
+        lines.push(format!("opinions: {}", mind.opinions_on_others.iter()
+                           .map(|(a, o)| format!("{} {:.1}", world.agents[*a].name, o))
+                           .collect::<Vec<_>>().join(", ")));
+        lines.push(format!("beliefs: {}  memories: {}", mind.beliefs.len(), mind.memories.len()));
+        lines.push(format!("inventory: {}", self.inventory()
+                           .map(|i| i.describe(self.id))
+                           .collect::<Vec<_>>().join(", ")));
+        lines.join("\n")
+    }
+
+    // How well the agent's village thinks of them, on average, among those
+    // who have formed an opinion either first or second hand.
+    pub fn reputation(&self, world: &World) -> f64 {
+        let opinions: Vec<f64> = world.agents.iter()
+            .filter(|a| a.id != self.id && a.storehouse == self.storehouse && a.health.borrow().alive)
+            .filter_map(|a| a.mind.borrow().opinions_on_others.get(&self.id).cloned())
+            .collect();
+        if opinions.is_empty() {
+            0.0
+        } else {
+            opinions.iter().sum::<f64>() / opinions.len() as f64
+        }
+    }
+
+    fn clothing_warmth(&self) -> f64 {
+        self.inventory.values().map(|i| i.kind().warmth).sum()
+    }
+
+    pub(crate) fn step_simulation(&self, world: &World) -> Vec<Box<dyn Event>> {
         let mut rng = rand::thread_rng();
         let mut daemon_urgency: Vec<f64> = Vec::with_capacity(self.daemons.len());
         let mut potential_daemons = Vec::with_capacity(self.daemons.len());
@@ -59,12 +223,9 @@ impl Agent {
         self.total_time.set(self.total_time.get() + 1.0);
 
         for daemon in &self.daemons {
-            match daemon.step_simulation(self, world) {
-                Some(urgency) => {
-                    daemon_urgency.push(urgency);
-                    potential_daemons.push(daemon);
-                },
-                None => (),
+            if let Some(urgency) = daemon.step_simulation(self, world) {
+                daemon_urgency.push(urgency);
+                potential_daemons.push(daemon);
             }
         }
 
@@ -82,9 +243,12 @@ pub struct Health {
     pub alive: bool,
     awake: bool,
     hunger: f64,
+    thirst: f64,
+    temperature: f64,
     sleepiness: f64,
     poop: f64,
     pain: f64,
+    ailments: Vec<ailments::Ailment>,
 }
 
 impl Health {
@@ -93,11 +257,34 @@ impl Health {
             alive: true,
             awake: true,
             hunger: 0.0,
+            thirst: 0.0,
+            temperature: 37.0,
             pain: 0.0,
             poop: 0.0,
             sleepiness: 0.0,
+            ailments: Vec::new(),
         }
     }
+
+    pub fn awake(&self) -> bool {
+        self.awake
+    }
+
+    pub fn hunger(&self) -> f64 {
+        self.hunger
+    }
+
+    pub fn sleepiness(&self) -> f64 {
+        self.sleepiness
+    }
+
+    pub fn pain(&self) -> f64 {
+        self.pain
+    }
+
+    fn is_contagious(&self) -> bool {
+        self.ailments.iter().any(|a| a.kind.contagious())
+    }
 }
 
 
@@ -106,7 +293,17 @@ struct Mind {
     current_goal: Option<(executive::Goal, Box<dyn executive::Strategy>)>,
     paused_goals: Vec<(executive::Goal, Box<dyn executive::Strategy>)>,
     opinions_on_others: HashMap<AgentId, f64>,
+    relationships: HashMap<AgentId, relationships::Relationship>,
+    partner: Option<AgentId>,
     opinions_on_places: HashMap<LocationId, f64>,
+    opinions_on_foods: HashMap<ItemTypeId, f64>,
+    beliefs: HashMap<beliefs::Claim, beliefs::Belief>,
+    memories: Vec<memories::Memory>,
+    next_memory: memories::MemoryId,
+    last_workday: i64,
+    loneliness: f64,
+    // Someone the agent is travelling with, and until when.
+    companion: Option<(AgentId, f64)>,
     agitation: f64,
     cheer: f64,
 }
@@ -118,9 +315,97 @@ impl Mind {
             current_goal: None,
             paused_goals: Vec::with_capacity(5),
             opinions_on_others: HashMap::with_capacity(100),
+            relationships: HashMap::with_capacity(100),
+            partner: None,
             opinions_on_places: HashMap::with_capacity(100),
+            opinions_on_foods: HashMap::with_capacity(10),
+            beliefs: HashMap::with_capacity(100),
+            memories: Vec::with_capacity(20),
+            next_memory: 0,
+            last_workday: -1,
+            loneliness: 0.0,
+            companion: None,
             agitation: 0.0,
             cheer: 1.0,
         }
     }
+
+    fn new_memory_id(&mut self) -> memories::MemoryId {
+        self.next_memory += 1;
+        self.next_memory
+    }
+}
+
+// Events that can be set off by hand from a debugging console.
+pub enum Forced {
+    Die(AgentId),
+    Afflict(AgentId, String),
+    Meet(AgentId, AgentId),
+    Chat(AgentId, AgentId),
+    Argue(AgentId, AgentId),
+    Fight(AgentId, AgentId),
+    Attack(CreatureId, AgentId),
+}
+
+impl Forced {
+    pub fn apply(&self, world: &mut World) -> Result<(), String> {
+        let agent = |id: AgentId| {
+            if id >= world.agents.len() {
+                Err(format!("no agent {}", id))
+            } else if !world.agents[id].health.borrow().alive {
+                Err(format!("{} is dead", world.agents[id].name))
+            } else {
+                Ok(id)
+            }
+        };
+        // Two agents can only deal with each other face to face.
+        let pair = |a: AgentId, b: AgentId| {
+            let (a, b) = (agent(a)?, agent(b)?);
+            if a == b {
+                Err(format!("{} can't do that with themself", world.agents[a].name))
+            } else if world.agents[a].location != world.agents[b].location {
+                Err(format!("{} and {} are not co-located", world.agents[a].name, world.agents[b].name))
+            } else {
+                Ok((a, b))
+            }
+        };
+        let event: Box<dyn Event> = match self {
+            Forced::Die(a) => Box::new(events::DieEvent { agent: agent(*a)? }),
+            Forced::Afflict(a, name) => {
+                let kind = ailments::AilmentKind::from_name(name).ok_or_else(|| format!("no ailment {}", name))?;
+                Box::new(events::AfflictEvent { agent: agent(*a)?, kind: kind })
+            },
+            Forced::Meet(a, b) => {
+                let (a, b) = pair(*a, *b)?;
+                Box::new(events::MeetEvent { agent: a, other: b })
+            },
+            Forced::Chat(a, b) => {
+                let (a, b) = pair(*a, *b)?;
+                Box::new(events::ChatEvent { agent: a, other: b })
+            },
+            Forced::Argue(a, b) => {
+                let (a, b) = pair(*a, *b)?;
+                Box::new(events::ArgueEvent { agent: a, other: b })
+            },
+            Forced::Fight(a, b) => {
+                let (a, b) = pair(*a, *b)?;
+                Box::new(events::FightEvent { agent: a, other: b })
+            },
+            Forced::Attack(c, a) => {
+                let a = agent(*a)?;
+                if *c >= world.creatures.len() {
+                    return Err(format!("no creature {}", c));
+                }
+                if !world.creatures[*c].alive {
+                    return Err(format!("creature {} is dead", c));
+                }
+                if world.creatures[*c].location != world.agents[a].location {
+                    return Err(format!("creature {} and {} are not co-located", c, world.agents[a].name));
+                }
+                Box::new(events::AttackEvent { creature: *c, agent: a })
+            },
+        };
+        event.apply(world);
+        Ok(())
+    }
 }
@@ -0,0 +1,30 @@
+use rand::Rng;
+
+pub struct Personality {
+    pub curiosity: f64,
+    pub sociability: f64,
+    pub introversion: f64,
+    pub appetite: f64,
+    pub bravery: f64,
+}
+
+impl Personality {
+    pub fn new() -> Personality {
+        let mut rng = rand::thread_rng();
+        Personality {
+            curiosity: rng.gen_range(0.0, 1.0),
+            sociability: rng.gen_range(0.0, 1.0),
+            introversion: rng.gen_range(0.0, 1.0),
+            appetite: rng.gen_range(0.0, 1.0),
+            bravery: rng.gen_range(0.0, 1.0),
+        }
+    }
+
+    // Maps a trait in [0, 1] to a multiplier in [0.5, 1.5], so rates scaled
+    // by it are unchanged for an average agent. Traits used some other way,
+    // like how much company an extrovert will put up with, make no such
+    // promise.
+    pub fn scale(value: f64) -> f64 {
+        0.5 + value
+    }
+}
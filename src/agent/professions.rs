@@ -0,0 +1,86 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use super::super::items::{self, ItemTypeId};
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Profession {
+    Farmer,
+    Baker,
+    Hunter,
+    Woodcutter,
+    Trader,
+}
+
+impl Profession {
+    // Most villagers have a trade but a few just live off the land.
+    pub fn random() -> Option<Profession> {
+        let mut rng = rand::thread_rng();
+        if rng.gen_range(0, 6) == 0 {
+            None
+        } else {
+            Some(*[
+                Profession::Farmer,
+                Profession::Farmer,
+                Profession::Baker,
+                Profession::Hunter,
+                Profession::Woodcutter,
+                Profession::Trader,
+            ].choose(&mut rng).unwrap())
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Profession::Farmer => "farmer",
+            Profession::Baker => "baker",
+            Profession::Hunter => "hunter",
+            Profession::Woodcutter => "woodcutter",
+            Profession::Trader => "trader",
+        }
+    }
+
+    pub fn work_description(&self) -> &'static str {
+        match self {
+            Profession::Farmer => "Worked in the fields.",
+            Profession::Baker => "Baked bread.",
+            Profession::Hunter => "Went hunting in the greenwood.",
+            Profession::Woodcutter => "Chopped wood.",
+            Profession::Trader => "Bartered at a distant storehouse.",
+        }
+    }
+
+    pub fn works_in_forest(&self) -> bool {
+        matches!(self, Profession::Hunter | Profession::Woodcutter)
+    }
+
+    pub fn hours(&self) -> u32 {
+        match self {
+            Profession::Farmer => 6,
+            Profession::Baker => 4,
+            Profession::Hunter => 6,
+            Profession::Woodcutter => 5,
+            Profession::Trader => 1,
+        }
+    }
+
+    // What a day's work uses up from the storehouse and what it makes.
+    pub fn inputs(&self) -> &'static [(ItemTypeId, usize)] {
+        match self {
+            Profession::Baker => &[(items::GRAIN, 3), (items::WOOD, 1)],
+            _ => &[],
+        }
+    }
+
+    pub fn outputs(&self) -> &'static [(ItemTypeId, usize)] {
+        match self {
+            // Farmers tend the village fields rather than putting in shifts.
+            Profession::Farmer => &[],
+            Profession::Baker => &[(items::BREAD, 2)],
+            // Hunters have to catch their meat; see HuntEvent.
+            Profession::Hunter => &[],
+            Profession::Woodcutter => &[(items::WOOD, 3)],
+            Profession::Trader => &[],
+        }
+    }
+}
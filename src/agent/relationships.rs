@@ -0,0 +1,32 @@
+use rand::Rng;
+
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+pub enum Courtship {
+    Acquainted,
+    Interested,
+    Courting,
+    Partnered,
+    Separated,
+}
+
+// Affection is the agent's overall opinion of the other, which lives in
+// `Mind::opinions_on_others`; the rest of how they feel is kept here.
+#[derive(Copy, Clone)]
+pub struct Relationship {
+    pub trust: f64,
+    pub respect: f64,
+    pub attraction: f64,
+    pub courtship: Courtship,
+}
+
+impl Relationship {
+    pub fn new() -> Relationship {
+        let mut rng = rand::thread_rng();
+        Relationship {
+            trust: 0.0,
+            respect: 0.0,
+            attraction: rng.gen_range(0.0, 1.0),
+            courtship: Courtship::Acquainted,
+        }
+    }
+}
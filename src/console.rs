@@ -0,0 +1,150 @@
+use std::io::{self, BufRead, Write};
+
+use novel_gen::{Forced, World};
+use novel_gen::items::ITEM_TYPES;
+
+use super::timeline::Timeline;
+
+const HELP: &str = "\
+agent <id>                  show an agent's health, goals, opinions and inventory
+location <id>               show a location's items, exits and occupants
+events <agent> [count]      show an agent's most recent events
+step [hours]                advance the simulation, one hour by default
+spawn <item> <location>     drop a new item, e.g. `spawn red mushroom 12`
+teleport <agent> <location> move an agent without walking
+force <event> <args>        die <a> | afflict <a> <ailment> | meet/chat/argue/fight <a> <b> | attack <creature> <a>
+continue                    leave the console and run to the end
+quit                        leave the console and stop the simulation here";
+
+// How the console was left.
+pub enum Exit {
+    Continue,
+    Quit,
+}
+
+// A command line for poking at a World between steps. Either way out leads
+// on to the usual summary and exports.
+pub fn run(world: &mut World, timeline: &mut Timeline, until: f64) -> Exit {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    println!("Type `help` for commands.");
+
+    loop {
+        print!("[day {} {:02}:00]> ", world.day(), world.hour() as u32);
+        io::stdout().flush().unwrap();
+        let line = match lines.next() {
+            Some(Ok(line)) => line,
+            _ => return Exit::Quit,
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.first() {
+            Some(&"continue") => return Exit::Continue,
+            Some(&"quit") => return Exit::Quit,
+            _ => (),
+        }
+        match execute(world, timeline, &words, until) {
+            Ok(output) => println!("{}", output),
+            Err(error) => println!("error: {}", error),
+        }
+    }
+}
+
+fn number(words: &[&str], index: usize) -> Result<usize, String> {
+    let word = words.get(index).ok_or_else(|| format!("{} needs more arguments", words[0]))?;
+    word.parse().map_err(|_| format!("{} is not a number", word))
+}
+
+fn execute(world: &mut World, timeline: &mut Timeline, words: &[&str], until: f64) -> Result<String, String> {
+    let command = match words.first() {
+        Some(command) => *command,
+        None => return Ok(String::new()),
+    };
+    let agent = |index: usize| -> Result<usize, String> {
+        let id = number(words, index)?;
+        if id < world.agents.len() { Ok(id) } else { Err(format!("no agent {}", id)) }
+    };
+    let location = |index: usize| -> Result<usize, String> {
+        let id = number(words, index)?;
+        if id < world.locations.len() { Ok(id) } else { Err(format!("no location {}", id)) }
+    };
+
+    match command {
+        "help" => Ok(HELP.to_string()),
+        "agent" => Ok(world.agents[agent(1)?].describe(world)),
+        "location" => Ok(describe_location(world, location(1)?)),
+        "events" => {
+            let id = agent(1)?;
+            let count = if words.len() > 2 { number(words, 2)? } else { 10 };
+            let from = world.event_count(id).saturating_sub(count);
+            Ok(world.event_log(id, from).join("\n"))
+        },
+        "step" => {
+            let hours = if words.len() > 1 { number(words, 1)? } else { 1 };
+            for _ in 0..hours {
+                if world.time >= until {
+                    break;
+                }
+                world.step_simulation();
+                timeline.record(world);
+            }
+            Ok(format!("Now day {} {:02}:00.", world.day(), world.hour() as u32))
+        },
+        "spawn" if words.len() > 2 => {
+            let at = location(words.len() - 1)?;
+            let name = words[1..words.len() - 1].join(" ");
+            let kind = ITEM_TYPES.iter()
+                .find(|t| t.name == name || t.plural == name)
+                .ok_or_else(|| format!("no item called {}", name))?;
+            let id = world.spawn_item(kind.id, at);
+            Ok(format!("Spawned {} ({}) at {}.", kind.with_article(), id, at))
+        },
+        "teleport" => {
+            let (a, to) = (agent(1)?, location(2)?);
+            if !world.agents[a].health.borrow().alive {
+                return Err(format!("{} is dead", world.agents[a].name));
+            }
+            world.teleport(a, to);
+            Ok(format!("Moved {} to {}.", world.agents[a].name, to))
+        },
+        "force" => {
+            let event = match words.get(1) {
+                Some(&"die") => Forced::Die(agent(2)?),
+                Some(&"afflict") if words.len() > 3 => Forced::Afflict(agent(2)?, words[3..].join(" ")),
+                Some(&"meet") => Forced::Meet(agent(2)?, agent(3)?),
+                Some(&"chat") => Forced::Chat(agent(2)?, agent(3)?),
+                Some(&"argue") => Forced::Argue(agent(2)?, agent(3)?),
+                Some(&"fight") => Forced::Fight(agent(2)?, agent(3)?),
+                Some(&"attack") => Forced::Attack(number(words, 2)?, agent(3)?),
+                _ => return Err("unknown event, see `help`".to_string()),
+            };
+            event.apply(world)?;
+            Ok("Done.".to_string())
+        },
+        _ => Err(format!("unknown command {}, see `help`", command)),
+    }
+}
+
+fn describe_location(world: &World, id: usize) -> String {
+    let location = &world.locations[id];
+    let mut lines = vec![format!("{} ({})", location.name, id)];
+    lines.push(format!("exits: {}", location.exits.iter().map(|e| e.to_string()).collect::<Vec<_>>().join(", ")));
+    if let Some(water) = location.water {
+        lines.push(format!("water: {}", water.name()));
+    }
+    if let Some(shelter) = location.shelter {
+        lines.push(format!("shelter: {}", shelter.name()));
+    }
+    if let Some(field) = &location.field {
+        lines.push(format!("field: {}", field.crop_name()));
+    }
+    lines.push(format!("agents: {}", location.agents().iter()
+                       .map(|a| format!("{} ({})", world.agents[*a].name, a))
+                       .collect::<Vec<_>>().join(", ")));
+    lines.push(format!("creatures: {}", location.creatures().iter()
+                       .map(|c| format!("{} ({})", world.creatures[*c].species.name(), c))
+                       .collect::<Vec<_>>().join(", ")));
+    lines.push(format!("items: {}", location.items()
+                       .map(|i| format!("{} ({})", i.kind().name, i.id))
+                       .collect::<Vec<_>>().join(", ")));
+    lines.join("\n")
+}
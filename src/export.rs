@@ -0,0 +1,173 @@
+use std::io::{self, Write};
+
+// A graph reduced to plain attributes, for formats other than DOT.
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+}
+
+type Attributes = Vec<(&'static str, Value)>;
+
+pub struct Graph {
+    pub directed: bool,
+    pub nodes: Vec<(isize, Attributes)>,
+    pub edges: Vec<(isize, isize, Attributes)>,
+}
+
+impl Value {
+    fn to_json(&self) -> String {
+        match self {
+            Value::Int(i) => i.to_string(),
+            Value::Float(f) if f.is_finite() => f.to_string(),
+            Value::Float(_) => "null".to_string(),
+            Value::Bool(b) => b.to_string(),
+            Value::Str(s) => json_string(s),
+        }
+    }
+
+    fn to_xml(&self) -> String {
+        match self {
+            Value::Int(i) => i.to_string(),
+            Value::Float(f) => f.to_string(),
+            Value::Bool(b) => b.to_string(),
+            Value::Str(s) => xml_escape(s),
+        }
+    }
+
+    fn graphml_type(&self) -> &'static str {
+        match self {
+            Value::Int(_) => "long",
+            Value::Float(_) => "double",
+            Value::Bool(_) => "boolean",
+            Value::Str(_) => "string",
+        }
+    }
+}
+
+pub fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn json_attributes(attributes: &Attributes) -> String {
+    attributes.iter()
+        .map(|(k, v)| format!("{}: {}", json_string(k), v.to_json()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl Graph {
+    pub fn write_json<W: Write>(&self, output: &mut W) -> io::Result<()> {
+        writeln!(output, "{{")?;
+        writeln!(output, "  \"directed\": {},", self.directed)?;
+        writeln!(output, "  \"nodes\": [")?;
+        for (i, (id, attributes)) in self.nodes.iter().enumerate() {
+            let comma = if i + 1 < self.nodes.len() { "," } else { "" };
+            writeln!(output, "    {{\"id\": {}, \"attributes\": {{{}}}}}{}", id, json_attributes(attributes), comma)?;
+        }
+        writeln!(output, "  ],")?;
+        writeln!(output, "  \"edges\": [")?;
+        for (i, (source, target, attributes)) in self.edges.iter().enumerate() {
+            let comma = if i + 1 < self.edges.len() { "," } else { "" };
+            writeln!(output, "    {{\"source\": {}, \"target\": {}, \"attributes\": {{{}}}}}{}",
+                     source, target, json_attributes(attributes), comma)?;
+        }
+        writeln!(output, "  ]")?;
+        writeln!(output, "}}")
+    }
+
+    pub fn write_graphml<W: Write>(&self, output: &mut W) -> io::Result<()> {
+        writeln!(output, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(output, "<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">")?;
+
+        // Every attribute needs a key declared up front, with its type taken
+        // from the first value seen.
+        let mut keys: Vec<(&'static str, &'static str, &'static str)> = Vec::new();
+        let mut declare = |domain: &'static str, attributes: &Attributes| {
+            for (name, value) in attributes {
+                if !keys.iter().any(|k| k.0 == domain && k.1 == *name) {
+                    keys.push((domain, name, value.graphml_type()));
+                }
+            }
+        };
+        for (_, attributes) in &self.nodes {
+            declare("node", attributes);
+        }
+        for (_, _, attributes) in &self.edges {
+            declare("edge", attributes);
+        }
+        for (domain, name, kind) in &keys {
+            writeln!(output, "  <key id=\"{}_{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>",
+                     domain, name, domain, name, kind)?;
+        }
+
+        let direction = if self.directed { "directed" } else { "undirected" };
+        writeln!(output, "  <graph edgedefault=\"{}\">", direction)?;
+        for (id, attributes) in &self.nodes {
+            writeln!(output, "    <node id=\"N{}\">", id)?;
+            for (name, value) in attributes {
+                writeln!(output, "      <data key=\"node_{}\">{}</data>", name, value.to_xml())?;
+            }
+            writeln!(output, "    </node>")?;
+        }
+        for (source, target, attributes) in &self.edges {
+            writeln!(output, "    <edge source=\"N{}\" target=\"N{}\">", source, target)?;
+            for (name, value) in attributes {
+                writeln!(output, "      <data key=\"edge_{}\">{}</data>", name, value.to_xml())?;
+            }
+            writeln!(output, "    </edge>")?;
+        }
+        writeln!(output, "  </graph>")?;
+        writeln!(output, "</graphml>")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_strings_are_quoted_and_escaped() {
+        assert_eq!(json_string("forest"), "\"forest\"");
+        assert_eq!(json_string("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(json_string("a\\b"), "\"a\\\\b\"");
+        assert_eq!(json_string("one\ntwo"), "\"one\\ntwo\"");
+        assert_eq!(json_string("\t\u{1}"), "\"\\u0009\\u0001\"");
+        assert_eq!(json_string("café"), "\"café\"");
+    }
+
+    #[test]
+    fn xml_special_characters_are_escaped() {
+        assert_eq!(xml_escape("field of grain"), "field of grain");
+        assert_eq!(xml_escape("<a & 'b'>"), "&lt;a &amp; &apos;b&apos;&gt;");
+        assert_eq!(xml_escape("\"&lt;\""), "&quot;&amp;lt;&quot;");
+    }
+
+    #[test]
+    fn json_leaves_out_numbers_it_cannot_write() {
+        assert_eq!(Value::Float(1.5).to_json(), "1.5");
+        assert_eq!(Value::Float(f64::NAN).to_json(), "null");
+        assert_eq!(Value::Float(f64::INFINITY).to_json(), "null");
+    }
+}
@@ -0,0 +1,172 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use super::items::{self, ItemTypeId};
+use super::Weather;
+
+// A year is 360 days, split evenly into four seasons.
+#[derive(Copy, Clone, PartialEq)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub fn from_day(day: i64) -> Season {
+        match (day % 360) / 90 {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Season::Spring => "spring",
+            Season::Summer => "summer",
+            Season::Autumn => "autumn",
+            Season::Winter => "winter",
+        }
+    }
+
+    pub fn planting(&self) -> bool {
+        matches!(self, Season::Spring | Season::Summer)
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum CropStage {
+    Fallow,
+    Sown,
+    Sprouting,
+    Ripe,
+    Failed,
+}
+
+pub struct Field {
+    pub crop: ItemTypeId,
+    pub stage: CropStage,
+    // Fraction of the way to harvest.
+    pub growth: f64,
+    pub ripe_for: f64,
+    pub since_tended: f64,
+}
+
+impl Default for Field {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Field {
+    pub fn new() -> Field {
+        Field {
+            crop: items::CARROT,
+            stage: CropStage::Fallow,
+            growth: 0.0,
+            ripe_for: 0.0,
+            since_tended: 0.0,
+        }
+    }
+
+    fn days_to_ripen(&self) -> f64 {
+        match self.crop {
+            items::GRAIN => 80.0,
+            _ => 50.0,
+        }
+    }
+
+    // What the crop is called while it's still in the ground.
+    pub fn crop_name(&self) -> &'static str {
+        match self.crop {
+            items::GRAIN => "grain",
+            _ => "carrots",
+        }
+    }
+
+    pub fn needs_work(&self, season: Season) -> bool {
+        match self.stage {
+            CropStage::Fallow => season.planting(),
+            CropStage::Sown | CropStage::Sprouting => self.since_tended > 24.0,
+            CropStage::Ripe | CropStage::Failed => true,
+        }
+    }
+
+    // Advances the crop by an hour. Untended crops grow at half speed, frost
+    // can kill anything still in the ground and ripe crops rot if nobody
+    // comes to harvest them.
+    pub fn step_simulation(&mut self, weather: Weather) {
+        let mut rng = rand::thread_rng();
+        match self.stage {
+            CropStage::Sown | CropStage::Sprouting => {
+                self.since_tended += 1.0;
+                let care = if self.since_tended > 48.0 { 0.5 } else { 1.0 };
+                self.growth += care / (24.0 * self.days_to_ripen());
+                if weather == Weather::Snow && rng.gen_range(0, 48) == 0 {
+                    self.stage = CropStage::Failed;
+                } else if self.growth >= 1.0 {
+                    self.stage = CropStage::Ripe;
+                    self.ripe_for = 0.0;
+                } else if self.growth >= 0.3 {
+                    self.stage = CropStage::Sprouting;
+                }
+            },
+            CropStage::Ripe => {
+                self.ripe_for += 1.0;
+                if self.ripe_for > 24.0 * 20.0 {
+                    self.stage = CropStage::Failed;
+                }
+            },
+            _ => (),
+        }
+    }
+
+    pub fn sow(&mut self) {
+        let mut rng = rand::thread_rng();
+        self.crop = *[items::CARROT, items::GRAIN].choose(&mut rng).unwrap();
+        self.stage = CropStage::Sown;
+        self.growth = 0.0;
+        self.since_tended = 0.0;
+    }
+
+    pub fn harvest_size(&self) -> usize {
+        let mut rng = rand::thread_rng();
+        match self.crop {
+            items::GRAIN => rng.gen_range(3, 6),
+            _ => rng.gen_range(6, 11),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seasons_follow_the_year() {
+        assert!(Season::from_day(0) == Season::Spring);
+        assert!(Season::from_day(89) == Season::Spring);
+        assert!(Season::from_day(90) == Season::Summer);
+        assert!(Season::from_day(180) == Season::Autumn);
+        assert!(Season::from_day(359) == Season::Winter);
+        assert!(Season::from_day(360) == Season::Spring);
+    }
+
+    #[test]
+    fn crops_are_named_as_they_grow() {
+        let mut field = Field::new();
+        assert_eq!(field.crop_name(), "carrots");
+        field.crop = items::GRAIN;
+        assert_eq!(field.crop_name(), "grain");
+    }
+
+    #[test]
+    fn only_fallow_fields_wait_for_planting_season() {
+        let field = Field::new();
+        assert!(field.needs_work(Season::Spring));
+        assert!(!field.needs_work(Season::Winter));
+    }
+}
@@ -0,0 +1,212 @@
+pub type ItemTypeId = usize;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Category {
+    Food,
+    Clothing,
+    Tool,
+    Material,
+}
+
+pub struct ItemType {
+    pub id: ItemTypeId,
+    pub name: &'static str,
+    pub plural: &'static str,
+    pub categories: &'static [Category],
+    pub weight: f64,
+    pub volume: f64,
+    pub value: f64,
+    pub food_value: f64,
+    pub warmth: f64,
+    pub poisonous: bool,
+    pub spoil_rate: f64,
+    pub capacity: f64,
+}
+
+impl ItemType {
+    pub fn is(&self, category: Category) -> bool {
+        self.categories.contains(&category)
+    }
+
+    pub fn with_article(&self) -> String {
+        let article = match self.name.chars().next() {
+            Some('a') | Some('e') | Some('i') | Some('o') | Some('u') => "an",
+            _ => "a",
+        };
+        format!("{} {}", article, self.name).to_string()
+    }
+
+    pub fn count(&self, n: usize) -> String {
+        match n {
+            1 => self.with_article(),
+            _ => format!("{} {}", n, self.plural).to_string(),
+        }
+    }
+}
+
+pub const BERRY: ItemTypeId = 0;
+pub const APPLE: ItemTypeId = 1;
+pub const CARROT: ItemTypeId = 2;
+pub const BROWN_MUSHROOM: ItemTypeId = 3;
+pub const RED_MUSHROOM: ItemTypeId = 4;
+pub const NIGHTSHADE_BERRY: ItemTypeId = 5;
+pub const CLOAK: ItemTypeId = 6;
+pub const WOOD: ItemTypeId = 7;
+pub const STONE: ItemTypeId = 8;
+pub const FIBER: ItemTypeId = 9;
+pub const BASKET: ItemTypeId = 10;
+pub const KNIFE: ItemTypeId = 11;
+pub const FISHING_ROD: ItemTypeId = 12;
+pub const FISH: ItemTypeId = 13;
+pub const GRAIN: ItemTypeId = 14;
+pub const BREAD: ItemTypeId = 15;
+pub const MEAT: ItemTypeId = 16;
+
+// New kinds of item only need an entry here and a constant above.
+pub static ITEM_TYPES: &[ItemType] = &[
+    ItemType {
+        id: BERRY, name: "berry", plural: "berries",
+        categories: &[Category::Food],
+        weight: 0.01, volume: 0.02, value: 0.1,
+        food_value: 2.0, warmth: 0.0, poisonous: false, spoil_rate: 1.0 / 48.0, capacity: 0.0,
+    },
+    ItemType {
+        id: APPLE, name: "apple", plural: "apples",
+        categories: &[Category::Food],
+        weight: 0.2, volume: 0.3, value: 0.5,
+        food_value: 10.0, warmth: 0.0, poisonous: false, spoil_rate: 1.0 / (24.0 * 10.0), capacity: 0.0,
+    },
+    ItemType {
+        id: CARROT, name: "carrot", plural: "carrots",
+        categories: &[Category::Food],
+        weight: 0.1, volume: 0.2, value: 0.5,
+        food_value: 20.0, warmth: 0.0, poisonous: false, spoil_rate: 1.0 / (24.0 * 14.0), capacity: 0.0,
+    },
+    ItemType {
+        id: BROWN_MUSHROOM, name: "brown mushroom", plural: "brown mushrooms",
+        categories: &[Category::Food],
+        weight: 0.05, volume: 0.1, value: 0.3,
+        food_value: 4.0, warmth: 0.0, poisonous: false, spoil_rate: 1.0 / 24.0, capacity: 0.0,
+    },
+    ItemType {
+        id: RED_MUSHROOM, name: "red mushroom", plural: "red mushrooms",
+        categories: &[Category::Food],
+        weight: 0.05, volume: 0.1, value: 0.0,
+        food_value: 4.0, warmth: 0.0, poisonous: true, spoil_rate: 1.0 / 24.0, capacity: 0.0,
+    },
+    ItemType {
+        id: NIGHTSHADE_BERRY, name: "nightshade berry", plural: "nightshade berries",
+        categories: &[Category::Food],
+        weight: 0.01, volume: 0.02, value: 0.0,
+        food_value: 4.0, warmth: 0.0, poisonous: true, spoil_rate: 1.0 / 24.0, capacity: 0.0,
+    },
+    ItemType {
+        id: CLOAK, name: "cloak", plural: "cloaks",
+        categories: &[Category::Clothing],
+        weight: 1.5, volume: 3.0, value: 5.0,
+        food_value: 0.0, warmth: 8.0, poisonous: false, spoil_rate: 0.0, capacity: 0.0,
+    },
+    ItemType {
+        id: WOOD, name: "stick of wood", plural: "sticks of wood",
+        categories: &[Category::Material],
+        weight: 1.0, volume: 1.0, value: 0.2,
+        food_value: 0.0, warmth: 0.0, poisonous: false, spoil_rate: 0.0, capacity: 0.0,
+    },
+    ItemType {
+        id: STONE, name: "stone", plural: "stones",
+        categories: &[Category::Material],
+        weight: 1.0, volume: 0.5, value: 0.1,
+        food_value: 0.0, warmth: 0.0, poisonous: false, spoil_rate: 0.0, capacity: 0.0,
+    },
+    ItemType {
+        id: FIBER, name: "bundle of fiber", plural: "bundles of fiber",
+        categories: &[Category::Material],
+        weight: 0.1, volume: 0.3, value: 0.2,
+        food_value: 0.0, warmth: 0.0, poisonous: false, spoil_rate: 0.0, capacity: 0.0,
+    },
+    ItemType {
+        id: BASKET, name: "basket", plural: "baskets",
+        categories: &[Category::Tool],
+        weight: 0.5, volume: 1.0, value: 3.0,
+        food_value: 0.0, warmth: 0.0, poisonous: false, spoil_rate: 0.0, capacity: 8.0,
+    },
+    ItemType {
+        id: KNIFE, name: "knife", plural: "knives",
+        categories: &[Category::Tool],
+        weight: 0.3, volume: 0.2, value: 4.0,
+        food_value: 0.0, warmth: 0.0, poisonous: false, spoil_rate: 0.0, capacity: 0.0,
+    },
+    ItemType {
+        id: FISHING_ROD, name: "fishing rod", plural: "fishing rods",
+        categories: &[Category::Tool],
+        weight: 0.5, volume: 1.0, value: 4.0,
+        food_value: 0.0, warmth: 0.0, poisonous: false, spoil_rate: 0.0, capacity: 0.0,
+    },
+    ItemType {
+        id: FISH, name: "fish", plural: "fish",
+        categories: &[Category::Food],
+        weight: 0.5, volume: 0.5, value: 1.0,
+        food_value: 15.0, warmth: 0.0, poisonous: false, spoil_rate: 1.0 / 24.0, capacity: 0.0,
+    },
+    ItemType {
+        id: GRAIN, name: "sack of grain", plural: "sacks of grain",
+        categories: &[Category::Material],
+        weight: 2.0, volume: 2.0, value: 1.0,
+        food_value: 0.0, warmth: 0.0, poisonous: false, spoil_rate: 0.0, capacity: 0.0,
+    },
+    ItemType {
+        id: BREAD, name: "loaf of bread", plural: "loaves of bread",
+        categories: &[Category::Food],
+        weight: 0.5, volume: 0.5, value: 2.0,
+        food_value: 25.0, warmth: 0.0, poisonous: false, spoil_rate: 1.0 / (24.0 * 4.0), capacity: 0.0,
+    },
+    ItemType {
+        id: MEAT, name: "cut of meat", plural: "cuts of meat",
+        categories: &[Category::Food],
+        weight: 1.0, volume: 0.5, value: 2.0,
+        food_value: 30.0, warmth: 0.0, poisonous: false, spoil_rate: 1.0 / 24.0, capacity: 0.0,
+    },
+];
+
+pub struct Recipe {
+    pub output: ItemTypeId,
+    pub inputs: &'static [(ItemTypeId, usize)],
+    // Tools that are needed for the work but not used up by it.
+    pub tools: &'static [ItemTypeId],
+    pub hours: u32,
+}
+
+pub static RECIPES: &[Recipe] = &[
+    Recipe { output: KNIFE, inputs: &[(STONE, 2), (WOOD, 1)], tools: &[], hours: 2 },
+    Recipe { output: BASKET, inputs: &[(FIBER, 4), (WOOD, 2)], tools: &[], hours: 3 },
+    Recipe { output: FISHING_ROD, inputs: &[(WOOD, 2), (FIBER, 2)], tools: &[KNIFE], hours: 2 },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_type_ids_match_their_index() {
+        for (i, t) in ITEM_TYPES.iter().enumerate() {
+            assert_eq!(t.id, i, "{} is out of place", t.name);
+        }
+    }
+
+    #[test]
+    fn recipes_use_known_items() {
+        for recipe in RECIPES {
+            assert!(recipe.output < ITEM_TYPES.len());
+            for (input, _) in recipe.inputs {
+                assert!(*input < ITEM_TYPES.len());
+            }
+        }
+    }
+
+    #[test]
+    fn articles_and_counts() {
+        assert_eq!(ITEM_TYPES[APPLE].with_article(), "an apple");
+        assert_eq!(ITEM_TYPES[BERRY].count(1), "a berry");
+        assert_eq!(ITEM_TYPES[BERRY].count(3), "3 berries");
+    }
+}
@@ -1,40 +1,163 @@
-#![feature(vec_remove_item)]
+// Struct literals spell out `id: id` and the like throughout.
+#![allow(clippy::redundant_field_names)]
 
 mod agent;
+pub mod farming;
+pub mod wildlife;
+pub mod items;
 
 use rand::{Rng};
-use rand::seq::IteratorRandom;
 use rand::seq::SliceRandom;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
-use self::agent::{Agent, AgentId};
+use self::agent::{Agent, AgentId, Profession};
+pub use self::agent::Forced;
+use self::wildlife::{Creature, CreatureId, Species};
+use self::items::{ItemType, ItemTypeId, ITEM_TYPES};
+use self::farming::{Field, Season};
 
 pub struct World {
     pub time: f64,
+    pub weather: Weather,
     pub agents: Vec<Agent>,
+    pub creatures: Vec<Creature>,
     pub locations: Vec<Location>,
     pub metrics: HashMap<&'static str, i32>,
+    next_item_id: ItemId,
 }
 
-type ItemId = usize;
-struct Item {
-    id: ItemId,
-    name: String,
-    food_value: f64,
+pub type ItemId = usize;
+pub struct Item {
+    pub id: ItemId,
+    pub kind: ItemTypeId,
+    pub crafted_by: Option<AgentId>,
+    picked_up: Option<f64>,
 }
 
-type LocationId = usize;
+impl Item {
+    fn new(id: ItemId, kind: ItemTypeId) -> Item {
+        Item {
+            id: id,
+            kind: kind,
+            crafted_by: None,
+            picked_up: None,
+        }
+    }
+
+    // Things an agent made themself are narrated as their own.
+    pub fn describe(&self, owner: AgentId) -> String {
+        if self.crafted_by == Some(owner) {
+            format!("the {} they made", self.kind().name).to_string()
+        } else {
+            self.kind().with_article()
+        }
+    }
+
+    pub fn kind(&self) -> &'static ItemType {
+        &ITEM_TYPES[self.kind]
+    }
+
+    // Food keeps until it's picked, then rots at its own pace.
+    pub fn freshness(&self, time: f64) -> f64 {
+        match self.picked_up {
+            Some(t) => (1.0 - (time - t) * self.kind().spoil_rate).max(0.0),
+            None => 1.0,
+        }
+    }
+
+    pub fn spoiled(&self, time: f64) -> bool {
+        self.freshness(time) <= 0.0
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Weather {
+    Clear,
+    Rain,
+    Snow,
+}
+
+impl Weather {
+    fn random(season: Season) -> Weather {
+        let mut rng = rand::thread_rng();
+        let choices: &[Weather] = match season {
+            Season::Winter => &[Weather::Clear, Weather::Snow, Weather::Snow],
+            Season::Summer => &[Weather::Clear, Weather::Clear, Weather::Rain],
+            _ => &[Weather::Clear, Weather::Clear, Weather::Rain, Weather::Snow],
+        };
+        *choices.choose(&mut rng).unwrap()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weather::Clear => "clear",
+            Weather::Rain => "rain",
+            Weather::Snow => "snow",
+        }
+    }
+
+    fn chill(&self) -> f64 {
+        match self {
+            Weather::Clear => 0.0,
+            Weather::Rain => 4.0,
+            Weather::Snow => 12.0,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum WaterSource {
+    Well,
+    Stream,
+}
+
+impl WaterSource {
+    pub fn name(&self) -> &'static str {
+        match self {
+            WaterSource::Well => "well",
+            WaterSource::Stream => "stream",
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Shelter {
+    House,
+    Cave,
+}
+
+impl Shelter {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Shelter::House => "house",
+            Shelter::Cave => "cave",
+        }
+    }
+}
+
+pub type LocationId = usize;
 pub struct Location {
     pub id: LocationId,
     pub name: String,
-    agents: Vec<AgentId>,
+    agents: HashSet<AgentId>,
+    creatures: HashSet<CreatureId>,
     items: HashMap<ItemId, Item>,
     pub exits: Vec<LocationId>,
+    pub water: Option<WaterSource>,
+    pub shelter: Option<Shelter>,
+    pub storehouse: bool,
+    // What the village owes each agent for goods brought to its storehouse.
+    pub favors: HashMap<AgentId, f64>,
+    // The storehouse of the village this location belongs to, if any.
+    pub village: Option<LocationId>,
+    pub field: Option<Field>,
+    // How many times agents have arrived here.
+    pub visits: u32,
 }
 
 trait Event {
-    fn apply(&self, world: &mut World) { }
-    fn to_string(&self, world: &World) -> String { "".to_string() }
+    fn apply(&self, _world: &mut World) { }
+    fn to_string(&self, _world: &World) -> String { "".to_string() }
 }
 
 
@@ -58,43 +181,178 @@ impl World {
         let location_count:i32 = scale*200;
         let agent_count:i32 = scale;
 
-        let mut rng = rand::thread_rng();
-
         let mut w = World {
             time: 0.0,
+            weather: Weather::Clear,
             agents: Vec::with_capacity(agent_count as usize),
+            creatures: Vec::with_capacity(location_count as usize / 10),
             locations: Vec::with_capacity(location_count as usize),
             metrics: HashMap::new(),
+            next_item_id: 0,
         };
 
-        let mut item_id = 0;
-
         let (locations, agents) = make_locations(location_count, agent_count);
         w.locations.extend(locations);
         w.agents.extend(agents);
+        w.next_item_id = w.locations.iter().map(|l| l.items.len()).sum();
+
+        for _ in 0..location_count / 10 {
+            w.spawn_creature(Species::random());
+        }
 
         w
     }
 
     pub fn step_simulation(&mut self) {
         self.time += 1.0;
-        let mut events = Vec::with_capacity(self.agents.len());
+        if rand::thread_rng().gen_range(0, 12) == 0 {
+            self.weather = Weather::random(self.season());
+        }
+        let weather = self.weather;
+        for field in self.locations.iter_mut().filter_map(|l| l.field.as_mut()) {
+            field.step_simulation(weather);
+        }
+        let mut events = Vec::with_capacity(self.agents.len() + self.creatures.len());
         for a in &self.agents {
             if a.health.borrow().alive {
                 events.extend(a.step_simulation(self));
             }
         }
+        for c in &self.creatures {
+            if c.alive {
+                events.extend(c.step_simulation(self));
+            }
+        }
+        // Births keep the greenwood from being hunted out.
+        let living = self.creatures.iter().filter(|c| c.alive).count();
+        if living < self.locations.len() / 10 && rand::thread_rng().gen_range(0, 48) == 0 {
+            self.spawn_creature(Species::random());
+        }
         for event in events {
             event.apply(self)
         }
     }
 
+    fn spawn_creature(&mut self, species: Species) {
+        let mut rng = rand::thread_rng();
+        let forest: Vec<LocationId> = self.locations.iter().filter(|l| l.name == "forest").map(|l| l.id).collect();
+        if let Some(location) = forest.choose(&mut rng) {
+            // Newborns take the place of the dead so the list doesn't grow
+            // without end.
+            let id = match self.creatures.iter().position(|c| !c.alive) {
+                Some(id) => {
+                    self.creatures[id] = Creature::new(id, species, *location);
+                    id
+                },
+                None => {
+                    let id = self.creatures.len();
+                    self.creatures.push(Creature::new(id, species, *location));
+                    id
+                },
+            };
+            self.locations[*location].creatures.insert(id);
+        }
+    }
+
+    fn new_item_id(&mut self) -> ItemId {
+        let id = self.next_item_id;
+        self.next_item_id += 1;
+        id
+    }
+
+    // Puts a new item of the given kind on the ground, for debugging.
+    pub fn spawn_item(&mut self, kind: ItemTypeId, location: LocationId) -> ItemId {
+        let id = self.new_item_id();
+        self.locations[location].items.insert(id, Item::new(id, kind));
+        id
+    }
+
+    // Moves an agent somewhere without walking there, for debugging. The
+    // trip still shows up in their trail, and they drop any route they had.
+    pub fn teleport(&mut self, agent: AgentId, location: LocationId) {
+        let start = self.agents[agent].location;
+        self.locations[start].agents.remove(&agent);
+        self.locations[location].agents.insert(agent);
+        self.agents[agent].location = location;
+        self.agents[agent].walk(start, location);
+        self.agents[agent].forget_plans();
+        self.locations[location].visits += 1;
+    }
+
+    pub fn day(&self) -> i64 {
+        (self.time / 24.0) as i64
+    }
+
+    pub fn season(&self) -> Season {
+        Season::from_day(self.day())
+    }
+
+    pub fn storehouses(&self) -> Vec<LocationId> {
+        self.locations.iter().filter(|l| l.storehouse).map(|l| l.id).collect()
+    }
+
+    pub fn hour(&self) -> f64 {
+        self.time % 24.0
+    }
+
+    pub fn temperature_at(&self, location: LocationId) -> f64 {
+        let hour = self.hour();
+        let mut temperature = if (6.0..20.0).contains(&hour) { 18.0 } else { 8.0 };
+        temperature -= self.weather.chill();
+        if self.locations[location].shelter.is_some() {
+            temperature += 10.0;
+        }
+        temperature
+    }
+
+    // Breadth first search over exits, returning the locations to move
+    // through (excluding the start) in order.
+    pub fn path(&self, start: LocationId, end: LocationId) -> Option<Vec<LocationId>> {
+        self.path_to_nearest(start, |l| l == end)
+    }
+
+    // Like `path`, but to whichever suitable location is fewest steps away.
+    pub fn path_to_nearest<F: Fn(LocationId) -> bool>(&self, start: LocationId, suits: F) -> Option<Vec<LocationId>> {
+        let mut came_from = HashMap::new();
+        let mut frontier = VecDeque::new();
+        came_from.insert(start, start);
+        frontier.push_back(start);
+        while let Some(current) = frontier.pop_front() {
+            if suits(current) {
+                let mut path = Vec::new();
+                let mut step = current;
+                while step != start {
+                    path.push(step);
+                    step = came_from[&step];
+                }
+                path.reverse();
+                return Some(path);
+            }
+            for exit in &self.locations[current].exits {
+                if !came_from.contains_key(exit) {
+                    came_from.insert(*exit, current);
+                    frontier.push_back(*exit);
+                }
+            }
+        }
+        None
+    }
+
     pub fn show_events(&self, agent_id: AgentId) {
-        let a = &self.agents[agent_id];
-        for event in &a.events {
-            println!("{}", event.to_string(self));
+        for line in self.event_log(agent_id, 0) {
+            println!("{}", line);
         }
     }
+
+    // Narration of an agent's events, starting from the given index.
+    pub fn event_log(&self, agent_id: AgentId, from: usize) -> Vec<String> {
+        let a = &self.agents[agent_id];
+        a.events.iter().skip(from).map(|e| e.to_string(self)).collect()
+    }
+
+    pub fn event_count(&self, agent_id: AgentId) -> usize {
+        self.agents[agent_id].events.len()
+    }
 }
 
 impl Location {
@@ -102,11 +360,31 @@ impl Location {
         Location {
             id: id,
             name: "a place".to_string(),
-            agents: Vec::with_capacity(10),
+            agents: HashSet::with_capacity(10),
+            creatures: HashSet::with_capacity(2),
             items: HashMap::with_capacity(10),
             exits: Vec::with_capacity(10),
+            water: None,
+            shelter: None,
+            storehouse: false,
+            favors: HashMap::new(),
+            village: None,
+            field: None,
+            visits: 0,
         }
     }
+
+    pub fn agents(&self) -> &HashSet<AgentId> {
+        &self.agents
+    }
+
+    pub fn creatures(&self) -> &HashSet<CreatureId> {
+        &self.creatures
+    }
+
+    pub fn items(&self) -> impl Iterator<Item=&Item> {
+        self.items.values()
+    }
 }
 
 fn make_locations(location_count: i32, agent_count: i32) -> (Vec<Location>, Vec<Agent>) {
@@ -127,21 +405,34 @@ fn make_locations(location_count: i32, agent_count: i32) -> (Vec<Location>, Vec<
         let id = locations.len();
         let mut location = Location::new(id);
         for _ in 0..rng.gen_range(0, 15) {
-            location.items.insert(item_id, Item {
-                id: item_id,
-                name: "berry".to_string(),
-                food_value: 2.0,
-            });
+            location.items.insert(item_id, Item::new(item_id, items::BERRY));
             item_id += 1;
         }
         for _ in 0..rng.gen_range(0, 15) {
-            location.items.insert(item_id, Item {
-                id: item_id,
-                name: "apple".to_string(),
-                food_value: 10.0,
-            });
+            location.items.insert(item_id, Item::new(item_id, items::APPLE));
             item_id += 1;
         }
+        for kind in &[items::WOOD, items::STONE, items::FIBER] {
+            for _ in 0..rng.gen_range(0, 3) {
+                location.items.insert(item_id, Item::new(item_id, *kind));
+                item_id += 1;
+            }
+        }
+        for _ in 0..rng.gen_range(0, 4) {
+            let kind = *[
+                items::BROWN_MUSHROOM,
+                items::RED_MUSHROOM,
+                items::NIGHTSHADE_BERRY,
+            ].choose(&mut rng).unwrap();
+            location.items.insert(item_id, Item::new(item_id, kind));
+            item_id += 1;
+        }
+        if rng.gen_range(0, 8) == 0 {
+            location.water = Some(WaterSource::Stream);
+        }
+        if rng.gen_range(0, 15) == 0 {
+            location.shelter = Some(Shelter::Cave);
+        }
         location.name = "forest".to_string();
         locations.push(location);
         the_greenwood.push(id);
@@ -159,7 +450,11 @@ fn make_locations(location_count: i32, agent_count: i32) -> (Vec<Location>, Vec<
     for village in &mut villages {
         let seed = *the_greenwood.choose(&mut rng).unwrap();
         locations[seed].name = "village".to_string();
-        the_greenwood.remove_item(&seed);
+        locations[seed].water = Some(WaterSource::Well);
+        locations[seed].shelter = Some(Shelter::House);
+        locations[seed].storehouse = true;
+        locations[seed].village = Some(seed);
+        the_greenwood.retain(|l| *l != seed);
         village.push(seed);
     }
 
@@ -168,7 +463,7 @@ fn make_locations(location_count: i32, agent_count: i32) -> (Vec<Location>, Vec<
         let id = locations.len();
 
         let to_split_id = *village.choose(&mut rng).unwrap();
-        let to_split_exits = (&locations[to_split_id]).exits.to_vec();
+        let to_split_exits = locations[to_split_id].exits.to_vec();
         
         let exits_a;
         let exits_b;
@@ -184,13 +479,22 @@ fn make_locations(location_count: i32, agent_count: i32) -> (Vec<Location>, Vec<
 
         let mut new_location = Location::new(id);
         for _ in 0..rng.gen_range(0, 15) {
-            new_location.items.insert(item_id, Item {
-                id: item_id,
-                name: "carrot".to_string(),
-                food_value: 20.0,
-            });
+            new_location.items.insert(item_id, Item::new(item_id, items::CARROT));
+            item_id += 1;
+        }
+        if rng.gen_range(0, 20) == 0 {
+            new_location.items.insert(item_id, Item::new(item_id, items::CLOAK));
             item_id += 1;
         }
+        if rng.gen_range(0, 3) == 0 {
+            new_location.shelter = Some(Shelter::House);
+        } else if rng.gen_range(0, 2) == 0 {
+            new_location.field = Some(Field::new());
+        }
+        new_location.village = Some(village[0]);
+        if rng.gen_range(0, 10) == 0 {
+            new_location.water = Some(WaterSource::Well);
+        }
         new_location.name = "village".to_string();
         for exit in &exits_a {
             locations[*exit].exits.push(to_split_id);
@@ -212,9 +516,86 @@ fn make_locations(location_count: i32, agent_count: i32) -> (Vec<Location>, Vec<
         let mut a = Agent::new(id as AgentId);
         let village = villages.choose_mut(&mut rng).unwrap();
         a.location = *village.choose(&mut rng).unwrap();
+        a.home = a.location;
+        a.storehouse = village[0];
+        a.profession = Profession::random();
+        locations[a.location].agents.insert(a.id);
         agents.push(a);
     }
 
 
     (locations, agents)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn food_spoils_only_once_picked_up() {
+        let mut berry = Item::new(0, items::BERRY);
+        assert_eq!(berry.freshness(1000.0), 1.0);
+
+        berry.picked_up = Some(10.0);
+        assert_eq!(berry.freshness(10.0), 1.0);
+        assert!((berry.freshness(34.0) - 0.5).abs() < 1e-9);
+        assert!(!berry.spoiled(57.0));
+        assert!(berry.spoiled(58.0));
+    }
+
+    // A line of locations, each joined to the next.
+    pub(crate) fn line_world(length: usize) -> World {
+        let mut locations: Vec<Location> = (0..length).map(Location::new).collect();
+        for i in 1..length {
+            locations[i - 1].exits.push(i);
+            locations[i].exits.push(i - 1);
+        }
+        World {
+            time: 0.0,
+            weather: Weather::Clear,
+            agents: Vec::new(),
+            creatures: Vec::new(),
+            locations: locations,
+            metrics: HashMap::new(),
+            next_item_id: 0,
+        }
+    }
+
+    #[test]
+    fn path_excludes_start_and_includes_end() {
+        let world = line_world(4);
+        assert_eq!(world.path(0, 3), Some(vec![1, 2, 3]));
+        assert_eq!(world.path(3, 1), Some(vec![2, 1]));
+        assert_eq!(world.path(2, 2), Some(vec![]));
+    }
+
+    #[test]
+    fn path_takes_the_shortcut() {
+        let mut world = line_world(5);
+        world.locations[0].exits.push(4);
+        world.locations[4].exits.push(0);
+        assert_eq!(world.path(0, 4), Some(vec![4]));
+    }
+
+    #[test]
+    fn nearest_path_stops_at_the_first_match() {
+        let world = line_world(6);
+        assert_eq!(world.path_to_nearest(2, |l| l == 0 || l == 5), Some(vec![1, 0]));
+        assert_eq!(world.path_to_nearest(4, |l| l == 0 || l == 5), Some(vec![5]));
+        assert_eq!(world.path_to_nearest(0, |l| l > 9), None);
+    }
+
+    #[test]
+    fn no_path_between_disconnected_places() {
+        let mut world = line_world(3);
+        world.locations.push(Location::new(3));
+        assert_eq!(world.path(0, 3), None);
+    }
+
+    #[test]
+    fn tools_never_spoil() {
+        let mut knife = Item::new(0, items::KNIFE);
+        knife.picked_up = Some(0.0);
+        assert!(!knife.spoiled(1e6));
+    }
+}
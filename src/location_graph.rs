@@ -1,29 +1,103 @@
 use std::borrow::Cow;
 use std::io::Write;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use novel_gen::{Location, World};
+use novel_gen::World;
+use novel_gen::items::Category;
+
+use super::export::{Graph, Value};
 
 type Nd = isize;
-type Ed = (isize,isize);
-struct Edges(Vec<Ed>, HashMap<isize, bool>);
+type Ed = (isize,isize,bool);
+struct Node {
+    name: String,
+    village: bool,
+    home: bool,
+    items: usize,
+    food: usize,
+    agents: usize,
+    visits: u32,
+}
+struct Edges(Vec<Ed>, HashMap<isize, Node>, u32);
+
+#[derive(Default)]
+pub struct Options {
+    // An agent whose wanderings should be traced across the map.
+    pub trace: Option<usize>,
+}
 
-pub fn render_to<W: Write>(locations: &Vec<Location>, world: &World, output: &mut W) {
+fn build(world: &World, options: &Options) -> Edges {
     let mut edges = Vec::new();
-    let mut labels = HashMap::with_capacity(locations.len());
+    let mut labels = HashMap::with_capacity(world.locations.len());
+
+    let homes: HashSet<usize> = world.agents.iter().map(|a| a.home).collect();
+    let mut path = HashSet::new();
+    if let Some(agent) = options.trace {
+        for step in world.agents[agent].trail.windows(2) {
+            path.insert((step[0], step[1]));
+            path.insert((step[1], step[0]));
+        }
+    }
 
-    for loc in locations {
-        let at = loc.name.contains("village");
-        labels.insert(loc.id as isize, at);
+    for loc in &world.locations {
+        labels.insert(loc.id as isize, Node {
+            name: loc.name.clone(),
+            village: loc.village.is_some(),
+            home: homes.contains(&loc.id),
+            items: loc.items().count(),
+            food: loc.items().filter(|i| i.kind().is(Category::Food)).count(),
+            agents: loc.agents().iter().filter(|a| world.agents[**a].health.borrow().alive).count(),
+            visits: loc.visits,
+        });
         for other in loc.exits.iter() {
-            edges.push((loc.id as isize, *other as isize));
+            // Exits run both ways, so only draw each once.
+            if loc.id < *other {
+                edges.push((loc.id as isize, *other as isize, path.contains(&(loc.id, *other))));
+            }
         }
     }
 
-    let edges = Edges(edges, labels);
+    let hottest = world.locations.iter().map(|l| l.visits).max().unwrap_or(0).max(1);
+    Edges(edges, labels, hottest)
+}
+
+pub fn render_to<W: Write>(world: &World, options: &Options, output: &mut W) {
+    let edges = build(world, options);
     dot::render(&edges, output).unwrap()
 }
 
+// The same graph as `render_to` draws, as plain attributes.
+pub fn export(world: &World, options: &Options) -> Graph {
+    let Edges(edges, labels, hottest) = build(world, options);
+    let mut nodes: Vec<_> = labels.into_iter()
+        .map(|(id, node)| {
+            let color = heat_color(node.visits, hottest);
+            (id, vec![
+                ("name", Value::Str(node.name)),
+                ("village", Value::Bool(node.village)),
+                ("home", Value::Bool(node.home)),
+                ("items", Value::Int(node.items as i64)),
+                ("food", Value::Int(node.food as i64)),
+                ("agents", Value::Int(node.agents as i64)),
+                ("visits", Value::Int(node.visits as i64)),
+                ("color", Value::Str(color)),
+            ])
+        })
+        .collect();
+    nodes.sort_by_key(|n| n.0);
+    let edges = edges.into_iter()
+        .map(|(s, t, on_path)| (s, t, vec![("on_path", Value::Bool(on_path))]))
+        .collect();
+    Graph { directed: false, nodes: nodes, edges: edges }
+}
+
+// Well trodden places glow red, untouched ones stay white.
+fn heat_color(visits: u32, hottest: u32) -> String {
+    let heat = visits as f64 / hottest as f64;
+    let cool = (255.0 * (1.0 - heat)) as u8;
+    format!("#ff{:02x}{:02x}", cool, cool).to_string()
+}
+
 impl<'a> dot::Labeller<'a, Nd, Ed> for Edges {
     fn graph_id(&'a self) -> dot::Id<'a> { dot::Id::new("example1").unwrap() }
 
@@ -31,34 +105,58 @@ impl<'a> dot::Labeller<'a, Nd, Ed> for Edges {
         dot::Id::new(format!("N{}", *n)).unwrap()
     }
 
+    fn node_label(&'a self, n: &Nd) -> dot::LabelText<'a> {
+        let node = &self.1[n];
+        dot::LabelText::escaped(format!("{} {}\\nitems: {} ({} food)\\nagents: {}\\nvisits: {}",
+                                        node.name, n, node.items, node.food, node.agents, node.visits))
+    }
+
+    fn node_shape(&'a self, n: &Nd) -> Option<dot::LabelText<'a>> {
+        let node = &self.1[n];
+        if node.home {
+            Some(dot::LabelText::label("house"))
+        } else if node.village {
+            Some(dot::LabelText::label("box"))
+        } else {
+            None
+        }
+    }
+
     fn node_style(&'a self, _n: &Nd) -> dot::Style {
         dot::Style::Filled
     }
 
     fn node_color(&'a self, n: &Nd) -> Option<dot::LabelText<'a>> {
-        let labels = &self.1;
-        let red = if labels[n] { "ff" } else { "00" };
-        let green = if labels[n] { "00" } else { "ff" };
-        let l = format!("#{}{}00", red, green).to_string();
-        Some(dot::LabelText::label(l))
+        Some(dot::LabelText::label(heat_color(self.1[n].visits, self.2)))
+    }
+
+    fn edge_style(&'a self, e: &Ed) -> dot::Style {
+        if e.2 { dot::Style::Bold } else { dot::Style::None }
+    }
+
+    fn edge_color(&'a self, e: &Ed) -> Option<dot::LabelText<'a>> {
+        if e.2 {
+            Some(dot::LabelText::label("blue"))
+        } else {
+            None
+        }
+    }
+
+    fn kind(&self) -> dot::Kind {
+        dot::Kind::Graph
     }
 }
 
 impl<'a> dot::GraphWalk<'a, Nd, Ed> for Edges {
     fn nodes(&self) -> dot::Nodes<'a,Nd> {
-        // (assumes that |N| \approxeq |E|)
-        let &Edges(ref v, _) = self;
-        let mut nodes = Vec::with_capacity(v.len());
-        for &(s,t) in v {
-            nodes.push(s); nodes.push(t);
-        }
+        let Edges(_, labels, _) = self;
+        let mut nodes: Vec<Nd> = labels.keys().cloned().collect();
         nodes.sort();
-        nodes.dedup();
         Cow::Owned(nodes)
     }
 
     fn edges(&'a self) -> dot::Edges<'a,Ed> {
-        let &Edges(ref edges, _) = self;
+        let Edges(edges, _, _) = self;
         Cow::Borrowed(&edges[..])
     }
 
@@ -1,24 +1,44 @@
+#![allow(clippy::redundant_field_names)]
+
 extern crate novel_gen;
 
 use std::time::SystemTime;
 
 use std::fs::File;
 
-//mod social_graph;
+mod console;
+mod export;
+mod social_graph;
 mod location_graph;
+mod timeline;
+mod watch;
 
 fn main() {
     let start = SystemTime::now();
 
     let mut w = novel_gen::World::new(10);
-    while w.time < 0.5*360.0*24.0 {
+    let end = 0.5*360.0*24.0;
+    if std::env::args().any(|a| a == "--watch") {
+        watch::run(&mut w, end);
+        return;
+    }
+
+    let mut timeline = timeline::Timeline::new(&w);
+    let mut until = end;
+    if std::env::args().any(|a| a == "--console") {
+        if let console::Exit::Quit = console::run(&mut w, &mut timeline, end) {
+            until = w.time;
+        }
+    }
+    while w.time < until {
         w.step_simulation();
+        timeline.record(&w);
         eprintln!("{}", w.time);
     }
 
     let total_secs = start.elapsed().unwrap().as_secs() as f64;
     let total_secs = total_secs + start.elapsed().unwrap().subsec_millis() as f64 / 1000.0;
-    let agent_time = w.agents.iter().map(|a| a.total_time.get() ).fold(0.0, |acc, x| acc + x);;
+    let agent_time = w.agents.iter().map(|a| a.total_time.get() ).fold(0.0, |acc, x| acc + x);
     let agent_days = agent_time / 24.0;
 
     let agent_idx = w.agents.iter().fold((0usize, 0.0), |acc, a| {
@@ -36,8 +56,25 @@ fn main() {
         let per_sec = *count as f64 / total_secs;
         println!("{:.2} {}s per second", per_sec, name);
     }
-   //let mut f = File::create("social.dot").unwrap();
-   // social_graph::render_to(&w.agents, &mut f);
+   let mut social_options = social_graph::Options::default();
+   // `--min-opinion <n>` draws every opinion at least that strong instead.
+   let args: Vec<String> = std::env::args().collect();
+   if let Some(i) = args.iter().position(|a| a == "--min-opinion") {
+       let magnitude = args.get(i + 1).and_then(|m| m.parse().ok()).expect("--min-opinion needs a number");
+       social_options.threshold = social_graph::Threshold::Magnitude(magnitude);
+   }
+   let mut f = File::create("social.dot").unwrap();
+   social_graph::render_to(&w, &social_options, &mut f);
+   let social = social_graph::export(&w, &social_options);
+   social.write_json(&mut File::create("social.json").unwrap()).unwrap();
+   social.write_graphml(&mut File::create("social.graphml").unwrap()).unwrap();
+
+   let location_options = location_graph::Options { trace: Some(agent_idx) };
    let mut f = File::create("location.dot").unwrap();
-   location_graph::render_to(&w.locations, &w, &mut f);
+   location_graph::render_to(&w, &location_options, &mut f);
+   let locations = location_graph::export(&w, &location_options);
+   locations.write_json(&mut File::create("location.json").unwrap()).unwrap();
+   locations.write_graphml(&mut File::create("location.graphml").unwrap()).unwrap();
+   timeline.write_csv(&mut File::create("timeline.csv").unwrap()).unwrap();
+   timeline.write_json(&mut File::create("timeline.json").unwrap()).unwrap();
 }
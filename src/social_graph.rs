@@ -1,40 +1,144 @@
 use std::borrow::Cow;
 use std::io::Write;
-use std::cell::RefCell;
-use std::rc::Rc;
 use std::collections::HashMap;
 
-use novel_gen::Agent;
+use novel_gen::World;
+
+use super::export::{Graph, Value};
 
 type Nd = isize;
-type Ed = (isize,isize,f64);
-struct Edges(Vec<Ed>, HashMap<isize, String>);
+#[derive(Copy, Clone)]
+enum Tie {
+    Opinion(f64),
+    Partner,
+    Household,
+}
+type Ed = (isize,isize,Tie);
+struct Node {
+    name: String,
+    alive: bool,
+}
+struct Edges(Vec<Ed>, HashMap<isize, Node>, f64);
 
-pub fn render_to<W: Write>(agents: &Vec<Rc<RefCell<Agent>>>, output: &mut W) {
-    let mut edges = Vec::new();
-    let mut labels = HashMap::with_capacity(agents.len());
+// Which opinions are worth drawing.
+pub enum Threshold {
+    // Keep opinions stronger than this fraction of all opinions.
+    Percentile(f64),
+    // Keep opinions at least this strong, however many there are.
+    Magnitude(f64),
+}
 
-    let mut weights = Vec::new();
-    for a in agents {
-        for op in a.borrow().mind.opinions_on_others.iter() {
-            weights.push((op.abs() * 1000.0) as u32);
+pub struct Options {
+    pub threshold: Threshold,
+    // Opinions at least this strong get a heavy line.
+    pub strong: f64,
+    pub households: bool,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options {
+            threshold: Threshold::Percentile(0.6),
+            strong: 4.0,
+            households: true,
         }
     }
-    weights.sort();
-    let thresh = weights[(weights.len() as f64 * 0.6) as usize] as f64 / 1000.0;
+}
+
+fn build(world: &World, options: &Options) -> Edges {
+    let mut edges = Vec::new();
+    let mut labels = HashMap::with_capacity(world.agents.len());
+
+    let thresh = match options.threshold {
+        Threshold::Magnitude(m) => m,
+        Threshold::Percentile(p) => {
+            let mut weights = Vec::new();
+            for a in &world.agents {
+                for op in a.opinions_on_others().values() {
+                    weights.push((op.abs() * 1000.0) as u32);
+                }
+            }
+            weights.sort();
+            let idx = ((weights.len() as f64 * p) as usize).min(weights.len().saturating_sub(1));
+            weights.get(idx).map_or(0.0, |w| *w as f64 / 1000.0)
+        },
+    };
 
-    for a in agents {
-        labels.insert(a.borrow().id as isize, a.borrow().name.clone());
-        for (id, op) in a.borrow().mind.opinions_on_others.iter().enumerate() {
+    for a in &world.agents {
+        labels.insert(a.id as isize, Node {
+            name: a.name.clone(),
+            alive: a.health.borrow().alive,
+        });
+        for (id, op) in a.opinions_on_others().iter() {
             if op.abs() > thresh {
-                edges.push((a.borrow().id as isize, id as isize, *op));
+                edges.push((a.id as isize, *id as isize, Tie::Opinion(*op)));
+            }
+        }
+        // Ties are drawn once, from the lower id.
+        if let Some(partner) = a.partner() {
+            if a.id < partner {
+                edges.push((a.id as isize, partner as isize, Tie::Partner));
+            }
+        }
+        if options.households {
+            for b in &world.agents[a.id + 1..] {
+                if b.home == a.home && a.partner() != Some(b.id) {
+                    edges.push((a.id as isize, b.id as isize, Tie::Household));
+                }
             }
         }
     }
-    let edges = Edges(edges, labels);
+    Edges(edges, labels, options.strong)
+}
+
+pub fn render_to<W: Write>(world: &World, options: &Options, output: &mut W) {
+    let edges = build(world, options);
     dot::render(&edges, output).unwrap()
 }
 
+// The same graph as `render_to` draws, as plain attributes.
+pub fn export(world: &World, options: &Options) -> Graph {
+    let Edges(edges, labels, strong) = build(world, options);
+    let mut nodes: Vec<_> = labels.into_iter()
+        .map(|(id, node)| (id, vec![
+            ("name", Value::Str(node.name)),
+            ("alive", Value::Bool(node.alive)),
+        ]))
+        .collect();
+    nodes.sort_by_key(|n| n.0);
+    let edges = edges.into_iter()
+        .map(|(s, t, tie)| {
+            let mut attributes = vec![
+                ("kind", Value::Str(tie_kind(tie).to_string())),
+                ("color", Value::Str(tie_color(tie).to_string())),
+            ];
+            if let Tie::Opinion(op) = tie {
+                attributes.push(("opinion", Value::Float(op)));
+                attributes.push(("strong", Value::Bool(op.abs() >= strong)));
+            }
+            (s, t, attributes)
+        })
+        .collect();
+    Graph { directed: true, nodes: nodes, edges: edges }
+}
+
+fn tie_kind(tie: Tie) -> &'static str {
+    match tie {
+        Tie::Opinion(_) => "opinion",
+        Tie::Partner => "partner",
+        Tie::Household => "household",
+    }
+}
+
+fn tie_color(tie: Tie) -> &'static str {
+    match tie {
+        Tie::Opinion(op) if op > 0.0 => "chartreuse",
+        Tie::Opinion(_) => "crimson",
+        Tie::Partner => "deeppink",
+        Tie::Household => "gray",
+    }
+}
+
 impl<'a> dot::Labeller<'a, Nd, Ed> for Edges {
     fn graph_id(&'a self) -> dot::Id<'a> { dot::Id::new("example1").unwrap() }
 
@@ -44,22 +148,40 @@ impl<'a> dot::Labeller<'a, Nd, Ed> for Edges {
 
     fn node_label(&'a self, n: &Nd) -> dot::LabelText<'a> {
         let labels = &self.1;
-        dot::LabelText::label(labels[n].clone())
+        dot::LabelText::label(labels[n].name.clone())
     }
 
-    fn edge_color(&'a self, e: &Ed) -> Option<dot::LabelText<'a>> {
-        if e.2 > 0.0 {
-            Some(dot::LabelText::label("chartreuse"))
+    fn node_style(&'a self, n: &Nd) -> dot::Style {
+        if self.1[n].alive { dot::Style::None } else { dot::Style::Dashed }
+    }
+
+    fn node_color(&'a self, n: &Nd) -> Option<dot::LabelText<'a>> {
+        if self.1[n].alive {
+            None
         } else {
-            Some(dot::LabelText::label("crimson"))
+            Some(dot::LabelText::label("gray"))
         }
     }
+
+    fn edge_style(&'a self, e: &Ed) -> dot::Style {
+        match e.2 {
+            Tie::Opinion(op) if op.abs() >= self.2 => dot::Style::Bold,
+            Tie::Opinion(op) if op.abs() >= self.2 / 2.0 => dot::Style::Solid,
+            Tie::Opinion(_) => dot::Style::Dotted,
+            Tie::Partner => dot::Style::Bold,
+            Tie::Household => dot::Style::Dashed,
+        }
+    }
+
+    fn edge_color(&'a self, e: &Ed) -> Option<dot::LabelText<'a>> {
+        Some(dot::LabelText::label(tie_color(e.2)))
+    }
 }
 
 impl<'a> dot::GraphWalk<'a, Nd, Ed> for Edges {
     fn nodes(&self) -> dot::Nodes<'a,Nd> {
         // (assumes that |N| \approxeq |E|)
-        let &Edges(ref v, _) = self;
+        let Edges(v, _, _) = self;
         let mut nodes = Vec::with_capacity(v.len());
         for &(s,t,_) in v {
             nodes.push(s); nodes.push(t);
@@ -70,7 +192,7 @@ impl<'a> dot::GraphWalk<'a, Nd, Ed> for Edges {
     }
 
     fn edges(&'a self) -> dot::Edges<'a,Ed> {
-        let &Edges(ref edges, _) = self;
+        let Edges(edges, _, _) = self;
         Cow::Borrowed(&edges[..])
     }
 
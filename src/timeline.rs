@@ -0,0 +1,77 @@
+use std::io::{self, Write};
+
+use novel_gen::World;
+
+use super::export::json_string;
+
+// Where an agent was and how they were doing at one hour.
+struct Sample {
+    time: f64,
+    location: usize,
+    goal: Option<&'static str>,
+    hunger: f64,
+    sleepiness: f64,
+    pain: f64,
+    awake: bool,
+}
+
+// Hour by hour record of every agent, for plotting needs and goal switching.
+pub struct Timeline {
+    names: Vec<String>,
+    samples: Vec<Vec<Sample>>,
+}
+
+impl Timeline {
+    pub fn new(world: &World) -> Timeline {
+        Timeline {
+            names: world.agents.iter().map(|a| a.name.clone()).collect(),
+            samples: world.agents.iter().map(|_| Vec::with_capacity(24 * 180)).collect(),
+        }
+    }
+
+    pub fn record(&mut self, world: &World) {
+        for agent in &world.agents {
+            let health = agent.health.borrow();
+            if !health.alive {
+                continue;
+            }
+            self.samples[agent.id].push(Sample {
+                time: world.time,
+                location: agent.location,
+                goal: agent.current_goal(),
+                hunger: health.hunger(),
+                sleepiness: health.sleepiness(),
+                pain: health.pain(),
+                awake: health.awake(),
+            });
+        }
+    }
+
+    pub fn write_csv<W: Write>(&self, output: &mut W) -> io::Result<()> {
+        writeln!(output, "agent,name,time,location,goal,hunger,sleepiness,pain,awake")?;
+        for (id, samples) in self.samples.iter().enumerate() {
+            for s in samples {
+                writeln!(output, "{},\"{}\",{},{},{},{:.3},{:.3},{:.3},{}",
+                         id, self.names[id].replace('"', "\"\""), s.time, s.location, s.goal.unwrap_or(""),
+                         s.hunger, s.sleepiness, s.pain, s.awake)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write_json<W: Write>(&self, output: &mut W) -> io::Result<()> {
+        writeln!(output, "[")?;
+        for (id, samples) in self.samples.iter().enumerate() {
+            writeln!(output, "  {{\"agent\": {}, \"name\": {}, \"samples\": [", id, json_string(&self.names[id]))?;
+            for (i, s) in samples.iter().enumerate() {
+                let goal = s.goal.map_or("null".to_string(), json_string);
+                let comma = if i + 1 < samples.len() { "," } else { "" };
+                writeln!(output, "    {{\"time\": {}, \"location\": {}, \"goal\": {}, \"hunger\": {:.3}, \"sleepiness\": {:.3}, \"pain\": {:.3}, \"awake\": {}}}{}",
+                         s.time, s.location, goal, s.hunger, s.sleepiness, s.pain, s.awake, comma)?;
+            }
+            let comma = if id + 1 < self.samples.len() { "," } else { "" };
+            writeln!(output, "  ]}}{}", comma)?;
+        }
+        writeln!(output, "]")
+    }
+}
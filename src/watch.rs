@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use novel_gen::World;
+use novel_gen::items::Category;
+
+const MAP_DEPTH: usize = 2;
+const FEED_LENGTH: usize = 12;
+
+enum Command {
+    Pause,
+    Step,
+    Faster,
+    Slower,
+    Next,
+    Previous,
+    Focus(usize),
+    Quit,
+}
+
+impl Command {
+    fn parse(line: &str) -> Option<Command> {
+        match line.trim() {
+            "p" | "" => Some(Command::Pause),
+            "s" => Some(Command::Step),
+            "+" => Some(Command::Faster),
+            "-" => Some(Command::Slower),
+            "n" => Some(Command::Next),
+            "b" => Some(Command::Previous),
+            "q" => Some(Command::Quit),
+            other => other.parse().ok().map(Command::Focus),
+        }
+    }
+}
+
+// Reads commands off stdin on their own thread so the simulation keeps
+// running between keystrokes.
+fn listen() -> Receiver<Command> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let stdin = io::stdin();
+        for line in stdin.lock().lines() {
+            let command = match line {
+                Ok(line) => Command::parse(&line),
+                Err(_) => return,
+            };
+            if let Some(command) = command {
+                if sender.send(command).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    receiver
+}
+
+// Runs the simulation in the terminal, redrawing after every hour, until
+// `until` or until the viewer quits.
+pub fn run(world: &mut World, until: f64) {
+    let commands = listen();
+    let mut focus = 0;
+    let mut paused = false;
+    let mut delay = 200;
+
+    while world.time < until {
+        let mut step = !paused;
+        while let Ok(command) = commands.try_recv() {
+            match command {
+                Command::Pause => paused = !paused,
+                Command::Step => step = true,
+                Command::Faster => delay = (delay / 2).max(1),
+                Command::Slower => delay = (delay * 2).min(5000),
+                Command::Next => focus = (focus + 1) % world.agents.len(),
+                Command::Previous => focus = (focus + world.agents.len() - 1) % world.agents.len(),
+                Command::Focus(id) if id < world.agents.len() => focus = id,
+                Command::Focus(_) => (),
+                Command::Quit => return,
+            }
+        }
+
+        if step {
+            world.step_simulation();
+        }
+        draw(world, focus, paused, delay).unwrap();
+        thread::sleep(Duration::from_millis(if paused { 50 } else { delay }));
+    }
+}
+
+fn draw(world: &World, focus: usize, paused: bool, delay: u64) -> io::Result<()> {
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let agent = &world.agents[focus];
+
+    // Clear the screen and home the cursor.
+    write!(out, "\x1b[2J\x1b[H")?;
+    let state = if paused { "paused".to_string() } else { format!("running, {}ms/hour", delay) };
+    writeln!(out, "Day {} {:02}:00  {}  {}  [{}]",
+             world.day(), world.hour() as u32, world.season().name(), world.weather.name(), state)?;
+    writeln!(out)?;
+
+    let health = agent.health.borrow();
+    let status = if !health.alive { "dead" } else if health.awake() { "awake" } else { "asleep" };
+    writeln!(out, "{} ({}) is {}, trying to {}", agent.name, agent.id, status, agent.current_goal().unwrap_or("do nothing"))?;
+    writeln!(out, "  hunger {:.1}  sleepiness {:.1}  pain {:.1}",
+             health.hunger(), health.sleepiness(), health.pain())?;
+    drop(health);
+    writeln!(out)?;
+
+    writeln!(out, "Around {}:", world.locations[agent.location].name)?;
+    let mut seen = HashSet::new();
+    draw_location(&mut out, world, agent.location, 0, &mut seen)?;
+    writeln!(out)?;
+
+    writeln!(out, "Agents:")?;
+    for a in &world.agents {
+        let health = a.health.borrow();
+        let marker = if a.id == focus { ">" } else { " " };
+        let goal = if health.alive { a.current_goal().unwrap_or("-") } else { "dead" };
+        writeln!(out, "{} {:3} {:24} {:12} hunger {:5.1}  sleepiness {:5.1}  pain {:4.1}",
+                 marker, a.id, a.name, goal, health.hunger(), health.sleepiness(), health.pain())?;
+    }
+    writeln!(out)?;
+
+    writeln!(out, "Events:")?;
+    let from = world.event_count(focus).saturating_sub(FEED_LENGTH);
+    for line in world.event_log(focus, from) {
+        writeln!(out, "  {}", line)?;
+    }
+    writeln!(out)?;
+
+    writeln!(out, "[enter/p] pause  [s] step  [+/-] speed  [n/b] next/previous agent  [<id>] focus  [q] quit")?;
+    out.flush()
+}
+
+// The map is a graph rather than a grid, so the region around an agent is
+// drawn as a tree of exits a couple of steps deep.
+fn draw_location<W: Write>(out: &mut W, world: &World, id: usize, depth: usize, seen: &mut HashSet<usize>) -> io::Result<()> {
+    seen.insert(id);
+    let location = &world.locations[id];
+
+    let mut features = Vec::new();
+    if let Some(water) = location.water {
+        features.push(water.name().to_string());
+    }
+    if let Some(shelter) = location.shelter {
+        features.push(shelter.name().to_string());
+    }
+    if location.storehouse {
+        features.push("storehouse".to_string());
+    }
+    if let Some(field) = &location.field {
+        features.push(format!("field of {}", field.crop_name()));
+    }
+    let items = location.items().count();
+    let food = location.items().filter(|i| i.kind().is(Category::Food)).count();
+    features.push(format!("{} items ({} food)", items, food));
+
+    let mut present: Vec<String> = location.agents().iter().map(|a| world.agents[*a].name.clone()).collect();
+    present.extend(location.creatures().iter().map(|c| world.creatures[*c].species.name().to_string()));
+
+    let indent = "    ".repeat(depth);
+    writeln!(out, "{}- {} {}: {}{}", indent, location.name, id, features.join(", "),
+             if present.is_empty() { String::new() } else { format!("; {}", present.join(", ")) })?;
+
+    if depth < MAP_DEPTH {
+        // Claim the exits before descending so nearer places aren't drawn
+        // again deeper in the tree.
+        let exits: Vec<usize> = location.exits.iter().cloned().filter(|e| seen.insert(*e)).collect();
+        for exit in exits {
+            draw_location(out, world, exit, depth + 1, seen)?;
+        }
+    }
+    Ok(())
+}
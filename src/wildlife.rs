@@ -0,0 +1,214 @@
+use std::cell::Cell;
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use super::{Event, LocationId, World};
+use super::agent::AttackEvent;
+
+pub type CreatureId = usize;
+
+#[derive(Copy, Clone, PartialEq)]
+pub enum Species {
+    Rabbit,
+    Deer,
+    Wolf,
+    Bear,
+}
+
+impl Species {
+    pub fn random() -> Species {
+        let mut rng = rand::thread_rng();
+        *[
+            Species::Rabbit, Species::Rabbit, Species::Rabbit, Species::Rabbit,
+            Species::Deer, Species::Deer, Species::Deer,
+            Species::Wolf, Species::Wolf,
+            Species::Bear,
+        ].choose(&mut rng).unwrap()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Species::Rabbit => "rabbit",
+            Species::Deer => "deer",
+            Species::Wolf => "wolf",
+            Species::Bear => "bear",
+        }
+    }
+
+    pub fn predator(&self) -> bool {
+        matches!(self, Species::Wolf | Species::Bear)
+    }
+
+    // Chance per hour of wandering off somewhere else.
+    fn restlessness(&self) -> f64 {
+        match self {
+            Species::Rabbit => 0.5,
+            Species::Deer => 0.4,
+            Species::Wolf => 0.6,
+            Species::Bear => 0.1,
+        }
+    }
+
+    // How likely a hunter is to bring one down in an hour.
+    pub fn catch_chance(&self) -> f64 {
+        match self {
+            Species::Rabbit => 0.3,
+            Species::Deer => 0.2,
+            Species::Wolf => 0.1,
+            Species::Bear => 0.05,
+        }
+    }
+
+    pub fn meat(&self) -> usize {
+        match self {
+            Species::Rabbit => 1,
+            Species::Deer => 4,
+            Species::Wolf => 2,
+            Species::Bear => 6,
+        }
+    }
+}
+
+pub struct Creature {
+    pub id: CreatureId,
+    pub species: Species,
+    pub location: LocationId,
+    pub alive: bool,
+    instincts: Vec<Box<dyn Instinct>>,
+}
+
+impl Creature {
+    pub fn new(id: CreatureId, species: Species, location: LocationId) -> Creature {
+        let mut instincts: Vec<Box<dyn Instinct>> = vec![
+            Box::new(Roam {}),
+        ];
+        if species.predator() {
+            instincts.push(Box::new(Prowl { last_attack: Cell::new(-24.0) }));
+        } else {
+            instincts.push(Box::new(Skittish {}));
+        }
+        Creature {
+            id: id,
+            species: species,
+            location: location,
+            alive: true,
+            instincts: instincts,
+        }
+    }
+
+    pub(crate) fn step_simulation(&self, world: &World) -> Vec<Box<dyn Event>> {
+        let mut rng = rand::thread_rng();
+        let mut urgencies = Vec::with_capacity(self.instincts.len());
+        let mut potential = Vec::with_capacity(self.instincts.len());
+        for instinct in &self.instincts {
+            if let Some(urgency) = instinct.step_simulation(self, world) {
+                urgencies.push(urgency);
+                potential.push(instinct);
+            }
+        }
+
+        let choices: Vec<usize> = (0..urgencies.len()).collect();
+        match choices.choose_weighted(&mut rng, |i| urgencies[*i]) {
+            Ok(i) => potential[*i].events(self, world),
+            Err(_) => vec![],
+        }
+    }
+}
+
+// The creature equivalent of an agent's daemons.
+trait Instinct {
+    fn step_simulation(&self, creature: &Creature, world: &World) -> Option<f64>;
+    fn events(&self, creature: &Creature, world: &World) -> Vec<Box<dyn Event>>;
+}
+
+// Creatures keep to the greenwood.
+fn forest_exit(creature: &Creature, world: &World) -> Option<LocationId> {
+    let mut rng = rand::thread_rng();
+    let exits: Vec<&LocationId> = world.locations[creature.location].exits.iter()
+        .filter(|e| world.locations[**e].name == "forest")
+        .collect();
+    exits.choose(&mut rng).map(|e| **e)
+}
+
+struct Roam;
+impl Instinct for Roam {
+    fn step_simulation(&self, creature: &Creature, _: &World) -> Option<f64> {
+        Some(creature.species.restlessness())
+    }
+
+    fn events(&self, creature: &Creature, world: &World) -> Vec<Box<dyn Event>> {
+        match forest_exit(creature, world) {
+            Some(end) => vec![
+                Box::new(CreatureMoveEvent { creature: creature.id, start: creature.location, end: end }),
+            ],
+            None => vec![],
+        }
+    }
+}
+
+// Prey bolts when people are about.
+struct Skittish;
+impl Instinct for Skittish {
+    fn step_simulation(&self, creature: &Creature, world: &World) -> Option<f64> {
+        if world.locations[creature.location].agents.is_empty() {
+            None
+        } else {
+            Some(0.5)
+        }
+    }
+
+    fn events(&self, creature: &Creature, world: &World) -> Vec<Box<dyn Event>> {
+        Roam {}.events(creature, world)
+    }
+}
+
+// Predators go for anyone they find, at most once a day.
+struct Prowl {
+    last_attack: Cell<f64>,
+}
+impl Instinct for Prowl {
+    fn step_simulation(&self, creature: &Creature, world: &World) -> Option<f64> {
+        if world.time - self.last_attack.get() < 24.0 {
+            return None;
+        }
+        let prey = world.locations[creature.location].agents.iter()
+            .any(|a| world.agents[*a].health.borrow().alive);
+        if prey {
+            Some(1.0)
+        } else {
+            None
+        }
+    }
+
+    fn events(&self, creature: &Creature, world: &World) -> Vec<Box<dyn Event>> {
+        let mut rng = rand::thread_rng();
+        let victims: Vec<&usize> = world.locations[creature.location].agents.iter()
+            .filter(|a| world.agents[**a].health.borrow().alive)
+            .collect();
+        match victims.choose(&mut rng) {
+            Some(victim) if rng.gen_range(0, 3) == 0 => {
+                self.last_attack.set(world.time);
+                vec![Box::new(AttackEvent { creature: creature.id, agent: **victim })]
+            },
+            _ => vec![],
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct CreatureMoveEvent {
+    pub creature: CreatureId,
+    pub start: LocationId,
+    pub end: LocationId,
+}
+impl Event for CreatureMoveEvent {
+    fn apply(&self, world: &mut World) {
+        let creature = &mut world.creatures[self.creature];
+        if !creature.alive {
+            return;
+        }
+        world.locations[self.start].creatures.remove(&self.creature);
+        world.locations[self.end].creatures.insert(self.creature);
+        creature.location = self.end;
+    }
+}